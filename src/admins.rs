@@ -1,11 +1,31 @@
 use cosmwasm_schema::cw_serde;
 
 use cosmwasm_std::{Addr, Deps, StdResult};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub enum Role {
+    /// Manages roles and the contract. Held by every admin
+    Owner,
+    /// Starts and cancels bidding periods, and disqualifies bids
+    Operator,
+    /// Ends bidding periods
+    Settler,
+    /// Controls where the proceeds of bidding periods are withdrawn to
+    Treasurer,
+}
 
 #[cw_serde]
 pub struct AdminList {
     pub admins: Vec<Addr>,
+    /// Number of approvals needed for the actions that can be proposed
+    #[serde(default = "default_threshold")]
+    pub threshold: u64,
+}
+
+fn default_threshold() -> u64 {
+    1
 }
 
 impl AdminList {
@@ -14,6 +34,35 @@ impl AdminList {
         let addr = addr.as_ref();
         self.admins.iter().any(|a| a.as_ref() == addr)
     }
+
+    /// returns the first admin listed more than once, if any
+    pub fn duplicate_admin(&self) -> Option<&Addr> {
+        self.admins
+            .iter()
+            .enumerate()
+            .find(|(i, admin)| self.admins[..*i].contains(admin))
+            .map(|(_, admin)| admin)
+    }
+
+    /// returns true if the threshold can be reached by the registered admins
+    /// Admins listed more than once are counted once
+    pub fn is_valid_threshold(&self) -> bool {
+        let mut distinct: Vec<&Addr> = self.admins.iter().collect();
+        distinct.sort();
+        distinct.dedup();
+        self.threshold >= 1 && self.threshold <= distinct.len() as u64
+    }
+}
+
+/// Transfer of an admin's seat, pending acceptance by the nominee
+#[cw_serde]
+pub struct AdminTransfer {
+    /// Admin giving up their seat
+    pub from: Addr,
+    /// Address that takes over the seat once it accepts the transfer
+    pub to: Addr,
+    /// Time or height after which the transfer can no longer be accepted
+    pub expiry: Expiration,
 }
 
 /// Verify that an address is authorized to execute a privileged operation
@@ -23,7 +72,21 @@ pub fn can_execute(deps: Deps, sender: &str) -> StdResult<bool> {
     Ok(can)
 }
 
+/// Verify that an address holds a role, either as an admin or through a grant
+pub fn has_role(deps: Deps, sender: &str, role: &Role) -> StdResult<bool> {
+    if can_execute(deps, sender)? {
+        return Ok(true);
+    }
+    let roles = ROLES
+        .may_load(deps.storage, &Addr::unchecked(sender))?
+        .unwrap_or_default();
+    Ok(roles.contains(role))
+}
+
 pub const ADMINS: Item<AdminList> = Item::new("admins");
+pub const PENDING_ADMIN_TRANSFER: Item<AdminTransfer> = Item::new("pending_admin_transfer");
+/// Roles granted to addresses that are not admins
+pub const ROLES: Map<&Addr, Vec<Role>> = Map::new("roles");
 
 #[cfg(test)]
 mod tests {
@@ -37,10 +100,25 @@ mod tests {
             .collect();
         let config = AdminList {
             admins: admins.clone(),
+            threshold: 2,
         };
 
         assert!(config.is_admin(admins[0].as_ref()));
         assert!(config.is_admin(admins[2].as_ref()));
         assert!(!config.is_admin("other"));
+
+        assert!(config.is_valid_threshold());
+        assert_eq!(config.duplicate_admin(), None);
+    }
+
+    #[test]
+    fn duplicate_admins() {
+        let config = AdminList {
+            admins: vec![Addr::unchecked("bob"), Addr::unchecked("bob")],
+            threshold: 2,
+        };
+
+        assert_eq!(config.duplicate_admin(), Some(&Addr::unchecked("bob")));
+        assert!(!config.is_valid_threshold());
     }
 }
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_vec, Addr, Api, Binary, StdResult, Timestamp, Uint128};
+use sha2::{Digest, Sha256};
+
+#[cw_serde]
+pub enum Attestor {
+    /// secp256k1 public key, in compressed or uncompressed form
+    Secp256k1 { pubkey: Binary },
+    /// ed25519 public key
+    Ed25519 { pubkey: Binary },
+}
+
+/// Off-chain authorization for a bidder, signed by the bidding period's attestor
+#[cw_serde]
+pub struct Attestation {
+    /// Maximum total bid the bidder is authorized to hold
+    pub max_amount: Uint128,
+    /// Time after which the attestation can no longer be used
+    pub expiry: Timestamp,
+    /// Unique number preventing the attestation from being replayed
+    pub nonce: u64,
+    /// Signature over the JSON-encoded `AttestationPayload`
+    pub signature: Binary,
+}
+
+/// Data signed by the attestor
+#[cw_serde]
+pub struct AttestationPayload {
+    pub contract: Addr,
+    pub period_id: u64,
+    pub bidder: Addr,
+    pub max_amount: Uint128,
+    pub expiry: Timestamp,
+    pub nonce: u64,
+}
+
+impl AttestationPayload {
+    /// Bytes that the attestor signs
+    pub fn to_bytes(&self) -> StdResult<Vec<u8>> {
+        to_vec(self)
+    }
+}
+
+impl Attestor {
+    /// returns true if `signature` is a valid signature of `payload` by this attestor
+    /// secp256k1 signatures are made over the SHA-256 hash of the payload
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        payload: &AttestationPayload,
+        signature: &[u8],
+    ) -> StdResult<bool> {
+        let message = payload.to_bytes()?;
+        let verified = match self {
+            Attestor::Secp256k1 { pubkey } => {
+                let hash = Sha256::digest(&message);
+                api.secp256k1_verify(&hash, signature, pubkey)
+            }
+            Attestor::Ed25519 { pubkey } => api.ed25519_verify(&message, signature, pubkey),
+        };
+        // Malformed keys or signatures are treated as invalid signatures
+        Ok(verified.unwrap_or(false))
+    }
+}
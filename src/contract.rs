@@ -1,23 +1,63 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
-    StdResult, Uint128,
+    coin, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
+    Response, StdResult, Storage, SubMsg, SubMsgResult, Uint128,
 };
-use cw2::set_contract_version;
-use cw_utils::must_pay;
+use std::cmp::Ordering;
 
-use crate::admins::{can_execute, AdminList, ADMINS};
+use cw2::{get_contract_version, set_contract_version};
+use cw4::{Cw4QueryMsg, MemberResponse};
+use cw_storage_plus::Bound;
+use cw_utils::{must_pay, Duration, Expiration, Scheduled};
+use semver::Version;
+
+use crate::admins::{
+    can_execute, has_role, AdminList, AdminTransfer, Role, ADMINS, PENDING_ADMIN_TRANSFER, ROLES,
+};
+use crate::attestation::{Attestation, AttestationPayload};
 use crate::error::ContractError;
+use crate::hooks::{
+    BidHookMsg, HookConfig, HookFailure, DEFAULT_HOOK_GAS_LIMIT, HOOKS, HOOK_REPLY_ID,
+};
+use crate::migrations;
 use crate::msg::{
-    BidResponse, BiddingPeriodResponse, BidsResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    BidHistoryResponse, BidResponse, BiddingPeriodResponse, BidsResponse, DenylistResponse,
+    ExecuteMsg, HookInfo, HooksResponse, InstantiateMsg, MigrateMsg, OperatorInfo,
+    OperatorsResponse, PauseStatusResponse, PendingAdminTransferResponse,
+    PendingSettlementResponse, ProposalResponse, ProposalsResponse, QueryMsg, ReceiptResponse,
+    RolesResponse, ScheduleResponse, SimulateBidResponse, SimulateEndBiddingResponse,
+    StatsResponse, SudoMsg, TreasuryResponse,
+};
+use crate::proposals::{Proposal, ProposalAction, PROPOSALS, PROPOSAL_COUNT};
+use crate::receipts::{record_receipts, RECEIPTS};
+use crate::state::{
+    append_contribution, can_manage_bid, clear_bids, is_paused, refresh_cutoff, remove_bid,
+    save_bid, Bid, BiddingPeriod, Config, Contribution, Payout, PendingSettlement, PeriodMetadata,
+    ProceedsShare, Schedule, BIDDING_PERIOD, BIDS, BID_HISTORY, BID_RANKING, CONFIG,
+    DEFAULT_EMERGENCY_WITHDRAW_DELAY, DENYLIST, OPERATORS, PAUSED_AT, PENDING_SETTLEMENT,
+    PERIOD_ID, PROCEEDS_SPLIT, SCHEDULE, STATS, TREASURY, USED_NONCES,
 };
-use crate::state::{Bid, BiddingPeriod, BIDDING_PERIOD, BIDS};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:juno_bid";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// pagination info for queries
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+// length limits of bidding period metadata
+const MAX_URI_LENGTH: usize = 256;
+const MAX_LINKS: usize = 5;
+const MAX_CATEGORY_LENGTH: usize = 32;
+const MAX_TAGS: usize = 10;
+const MAX_TAG_LENGTH: usize = 32;
+
+// limits of the proceeds split
+const MAX_SPLIT_SHARES: usize = 10;
+const TOTAL_BPS: u64 = 10_000;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -28,7 +68,21 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     // Save the list of admins
-    ADMINS.save(deps.storage, &AdminList { admins: msg.admins })?;
+    let admins = AdminList {
+        admins: msg.admins,
+        threshold: msg.threshold.unwrap_or(1),
+    };
+    validate_admin_list(&admins)?;
+    ADMINS.save(deps.storage, &admins)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            emergency_withdraw_delay: msg
+                .emergency_withdraw_delay
+                .unwrap_or(DEFAULT_EMERGENCY_WITHDRAW_DELAY),
+        },
+    )?;
 
     Ok(Response::new().add_attribute("method", "instantiate"))
 }
@@ -46,8 +100,97 @@ pub fn execute(
             accepted_bids,
             withdrawal_address,
         } => execute_end_bidding(deps, env, info, accepted_bids, withdrawal_address),
-        ExecuteMsg::Bid { address } => execute_bid(deps, env, info, address),
+        ExecuteMsg::Propose { action, expires } => {
+            execute_propose(deps, env, info, action, expires)
+        }
+        ExecuteMsg::Approve { proposal_id } => execute_approve(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateBiddingPeriod { config } => {
+            execute_update_bidding_period(deps, env, info, config)
+        }
+        ExecuteMsg::CreateSchedule {
+            template,
+            interval,
+            count,
+        } => execute_create_schedule(deps, env, info, template, interval, count),
+        ExecuteMsg::CancelSchedule {} => execute_cancel_schedule(deps, env, info),
+        ExecuteMsg::CancelBidding {} => execute_cancel_bidding(deps, env, info),
+        ExecuteMsg::GrantRole { address, role } => {
+            execute_grant_role(deps, env, info, address, role)
+        }
+        ExecuteMsg::RevokeRole { address, role } => {
+            execute_revoke_role(deps, env, info, address, role)
+        }
+        ExecuteMsg::UpdateDenylist { add, remove } => {
+            execute_update_denylist(deps, env, info, add, remove)
+        }
+        ExecuteMsg::SetTreasury { address } => execute_set_treasury(deps, env, info, address),
+        ExecuteMsg::SetProceedsSplit { split } => {
+            execute_set_proceeds_split(deps, env, info, split)
+        }
+        ExecuteMsg::ProposeAdminTransfer { new_admin, expiry } => {
+            execute_propose_admin_transfer(deps, env, info, new_admin, expiry)
+        }
+        ExecuteMsg::AcceptAdminTransfer {} => execute_accept_admin_transfer(deps, env, info),
+        ExecuteMsg::CancelAdminTransfer {} => execute_cancel_admin_transfer(deps, env, info),
+        ExecuteMsg::DisqualifyBid {
+            address,
+            reason,
+            denylist,
+        } => execute_disqualify_bid(deps, env, info, address, reason, denylist),
+        ExecuteMsg::AddHook {
+            address,
+            on_error,
+            gas_limit,
+        } => execute_add_hook(deps, env, info, address, on_error, gas_limit),
+        ExecuteMsg::RemoveHook { address } => execute_remove_hook(deps, env, info, address),
+        ExecuteMsg::VetoSettlement {} => execute_veto_settlement(deps, env, info),
+        ExecuteMsg::Pause {} => execute_pause(deps, env, info),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, env, info),
+        ExecuteMsg::Bid {
+            address,
+            attestation,
+            terms_hash,
+        } => execute_bid(deps, env, info, address, attestation, terms_hash),
+        ExecuteMsg::EmergencyWithdraw { address } => {
+            execute_emergency_withdraw(deps, env, info, address)
+        }
+        ExecuteMsg::TransferBid { from, to } => execute_transfer_bid(deps, env, info, from, to),
+        ExecuteMsg::ApproveOperator { operator, expires } => {
+            execute_approve_operator(deps, env, info, operator, expires)
+        }
+        ExecuteMsg::RevokeOperator { operator } => {
+            execute_revoke_operator(deps, env, info, operator)
+        }
+        ExecuteMsg::ClaimReceipt { period_id } => execute_claim_receipt(deps, env, info, period_id),
+        ExecuteMsg::StartScheduledBidding {} => execute_start_scheduled_bidding(deps, env, info),
+        ExecuteMsg::ReleaseProceeds {} => execute_release_proceeds(deps, env, info),
+    }
+}
+
+/// Verify that an address holds all the given roles
+fn has_roles(deps: Deps, sender: &Addr, roles: &[Role]) -> StdResult<bool> {
+    for role in roles {
+        if !has_role(deps, sender.as_ref(), role)? {
+            return Ok(false);
+        }
     }
+    Ok(true)
+}
+
+/// Verify that an address can execute an action that is subject to the approval
+/// threshold without a proposal
+fn can_execute_directly(
+    deps: Deps,
+    sender: &Addr,
+    action: &ProposalAction,
+) -> Result<(), ContractError> {
+    if !has_roles(deps, sender, &action.required_roles())? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if ADMINS.load(deps.storage)?.threshold > 1 {
+        return Err(ContractError::ProposalRequired {});
+    }
+    Ok(())
 }
 
 fn execute_start_bidding(
@@ -57,35 +200,79 @@ fn execute_start_bidding(
     config: BiddingPeriod,
 ) -> Result<Response, ContractError> {
     // Method is privileged
-    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
-        return Err(ContractError::Unauthorized {});
-    };
+    let action = ProposalAction::StartBidding { config };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
 
+    execute_action(deps, env, info.sender, action)
+}
+
+fn start_bidding(
+    deps: DepsMut,
+    env: Env,
+    config: BiddingPeriod,
+) -> Result<Response, ContractError> {
     // Check if another bidding period already exists
     let bidding_period = BIDDING_PERIOD.may_load(deps.storage)?;
     if bidding_period.is_some() {
         return Err(ContractError::BiddingPeriodActive {});
     };
 
-    // Verify that the date provided is not in the past
-    if config.expires_at <= env.block.time {
-        return Err(ContractError::CustomErrorParam {
-            val: "Bidding period end time is in the past".into(),
-        });
+    // Check that the proceeds of the previous bidding period have been released or vetoed
+    if PENDING_SETTLEMENT.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::SettlementPending {});
+    }
+
+    // Verify that the bidding period ends, and not in the past
+    if matches!(config.expires_at, Expiration::Never {}) || config.expires_at.is_expired(&env.block)
+    {
+        return Err(ContractError::InvalidExpiry {});
     };
 
+    // Verify that the bidding period starts before it expires, when both are
+    // expressed in the same unit
+    if let Some(starts_at) = &config.starts_at {
+        let starts_after_expiry = match (starts_at, &config.expires_at) {
+            (Scheduled::AtHeight(start), Expiration::AtHeight(end)) => start >= end,
+            (Scheduled::AtTime(start), Expiration::AtTime(end)) => start >= end,
+            _ => false,
+        };
+        if starts_after_expiry {
+            return Err(ContractError::InvalidExpiry {});
+        }
+    }
+
     // Verify that at least 1 bid will be accepted
     if config.accepted_bidders < 1 {
-        return Err(ContractError::CustomErrorParam {
-            val: "At least 1 bid needs to be able to be accepted".into(),
-        });
+        return Err(ContractError::InvalidAcceptedBidders {});
+    }
+
+    // Verify that the group contract address is valid
+    if let Some(group) = &config.group {
+        deps.api.addr_validate(group.contract.as_str())?;
+    }
+
+    // Verify that the guardian address is valid
+    if let Some(dispute) = &config.dispute {
+        deps.api.addr_validate(dispute.guardian.as_str())?;
+    }
+
+    // Verify that the receipt collection address is valid
+    if let Some(collection) = &config.receipt_collection {
+        deps.api.addr_validate(collection.as_str())?;
+    }
+
+    if let Some(metadata) = &config.metadata {
+        validate_metadata(metadata)?;
     }
 
     // If not, create the new bidding period
     BIDDING_PERIOD.save(deps.storage, &config)?;
+    let period_id = PERIOD_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PERIOD_ID.save(deps.storage, &period_id)?;
 
     Ok(Response::new()
         .add_attribute("method", "start_bidding")
+        .add_attribute("bidding_period_id", period_id.to_string())
         .add_attribute("bidding_period_name", config.name)
         .add_attribute(
             "bidding_period_description",
@@ -100,158 +287,2093 @@ fn execute_start_bidding(
 
 fn execute_end_bidding(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     accepted_bids: Vec<Addr>,
     withdrawal_address: Option<Addr>,
 ) -> Result<Response, ContractError> {
     // Method is privileged
-    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
-        return Err(ContractError::Unauthorized {});
+    let action = ProposalAction::EndBidding {
+        accepted_bids,
+        withdrawal_address,
     };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
 
-    // Verify that there is a bidding period to end
-    let bidding_period = BIDDING_PERIOD.load(deps.storage)?;
+    execute_action(deps, env, info.sender, action)
+}
 
-    // Verify that the list of accepted bids is no longer than the config dictates
-    if accepted_bids.len() as u64 > bidding_period.accepted_bidders {
-        return Err(ContractError::CustomErrorParam {
-            val: "You cannot accept more bids than the bidding period configuration allows".into(),
-        });
-    }
+/// Ends the bidding period, withdrawing the proceeds to `withdrawal_address`,
+/// or to the treasury if it is not specified, or to `sender` if there is no treasury
+/// and `sender` is a treasurer
+fn end_bidding(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    accepted_bids: Vec<Addr>,
+    withdrawal_address: Option<Addr>,
+) -> Result<Response, ContractError> {
+    // Verify that there is a bidding period to end, and work out its outcome
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    let settlement = compute_settlement(
+        deps.as_ref(),
+        &bidding_period,
+        &accepted_bids,
+        withdrawal_address.as_ref(),
+    )?;
+    let total_to_withdraw = settlement.proceeds;
+    let withdrawal_address =
+        resolve_withdrawal_address(deps.as_ref(), Some(&sender), withdrawal_address)?;
+    let split = PROCEEDS_SPLIT.may_load(deps.storage)?.unwrap_or_default();
 
     // Remove current bidding period
     BIDDING_PERIOD.remove(deps.storage);
 
-    // Keep the funds of all accepted bids
-    let mut total_to_withdraw = Uint128::zero();
-    for accepted_bid in accepted_bids {
-        let bid = BIDS.load(deps.storage, &accepted_bid)?;
-        total_to_withdraw += bid.amount;
-        BIDS.remove(deps.storage, &accepted_bid);
-    }
-
     // Reimburse all rejected bids
-    let mut msgs: Vec<BankMsg> = vec![];
-    let bid_keys = BIDS.keys(deps.storage, None, None, Order::Ascending);
-    for key in bid_keys {
-        match key {
-            Ok(key) => {
-                let bid = BIDS.load(deps.storage, &key)?;
-
-                let refund_bidder = BankMsg::Send {
-                    to_address: key.to_string(),
-                    amount: vec![coin(bid.amount.u128(), bidding_period.denom.clone())],
-                };
-                msgs.push(refund_bidder);
-            }
-            Err(_) => return Err(ContractError::NotFound {}),
-        }
-    }
+    let msgs: Vec<BankMsg> = settlement
+        .refunds
+        .iter()
+        .map(|refund| payout_msg(refund, &bidding_period.denom))
+        .collect();
 
     // Clear the bids
-    BIDS.clear(deps.storage);
+    clear_bids(deps.storage);
+
+    let res = Response::new()
+        .add_attribute("method", "end_bidding")
+        .add_messages(msgs);
+
+    // If the bidding period has a dispute window, hold the proceeds until it closes
+    let period_id = PERIOD_ID.load(deps.storage)?;
+    if let Some(dispute) = bidding_period.dispute {
+        let releasable_at = env.block.time.plus_seconds(dispute.duration);
+        PENDING_SETTLEMENT.save(
+            deps.storage,
+            &PendingSettlement {
+                period_id,
+                period_name: bidding_period.name,
+                winners: settlement.accepted,
+                proceeds: total_to_withdraw,
+                denom: bidding_period.denom,
+                withdrawal_address,
+                split,
+                refund_to: bidding_period.refund_to,
+                guardian: dispute.guardian,
+                releasable_at,
+                receipt_collection: bidding_period.receipt_collection,
+            },
+        )?;
+
+        return Ok(res
+            .add_attribute("withdrawn", Uint128::zero().to_string())
+            .add_attribute("held", total_to_withdraw.to_string())
+            .add_attribute("releasable_at", releasable_at.to_string()));
+    }
+
+    // Let the winners claim their receipts
+    if let Some(collection) = &bidding_period.receipt_collection {
+        record_receipts(
+            deps.storage,
+            period_id,
+            &bidding_period.name,
+            &bidding_period.denom,
+            collection,
+            &settlement.accepted,
+        )?;
+    }
 
     // Withdraw all remaining funds
-    let withdrawal = BankMsg::Send {
-        to_address: match withdrawal_address {
-            Some(address) => address.into(),
-            None => info.sender.into(),
-        },
-        amount: vec![coin(total_to_withdraw.u128(), bidding_period.denom)],
-    };
+    let withdrawal = withdrawal_msgs(
+        &withdrawal_address,
+        &split,
+        total_to_withdraw,
+        &bidding_period.denom,
+    );
 
-    Ok(Response::new()
-        .add_attribute("method", "end_bidding")
+    // Notify the hooks
+    let hooks = BidHookMsg::PeriodSettled {
+        period: period_id,
+        winners: settlement.accepted,
+    }
+    .into_submsgs(deps.storage)?;
+
+    Ok(res
         .add_attribute("withdrawn", total_to_withdraw.to_string())
+        .add_messages(withdrawal)
+        .add_submessages(hooks))
+}
+
+/// Address the proceeds are withdrawn to: `withdrawal_address` if set, else the treasury
+/// Without a treasury, only a treasurer can have the proceeds withdrawn to themselves
+fn resolve_withdrawal_address(
+    deps: Deps,
+    sender: Option<&Addr>,
+    withdrawal_address: Option<Addr>,
+) -> Result<Addr, ContractError> {
+    if let Some(address) = withdrawal_address.or(TREASURY.may_load(deps.storage)?) {
+        return Ok(address);
+    }
+    match sender {
+        Some(sender) if has_role(deps, sender.as_str(), &Role::Treasurer)? => Ok(sender.clone()),
+        _ => Err(ContractError::WithdrawalAddressRequired {}),
+    }
+}
+
+fn execute_release_proceeds(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // The proceeds cannot be released while the contract is paused
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    // Verify that the dispute window has closed
+    let settlement = PENDING_SETTLEMENT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingSettlement {})?;
+    if env.block.time < settlement.releasable_at {
+        return Err(ContractError::DisputeWindowOpen {
+            releasable_at: settlement.releasable_at,
+        });
+    }
+
+    PENDING_SETTLEMENT.remove(deps.storage);
+
+    // Let the winners claim their receipts
+    if let Some(collection) = &settlement.receipt_collection {
+        record_receipts(
+            deps.storage,
+            settlement.period_id,
+            &settlement.period_name,
+            &settlement.denom,
+            collection,
+            &settlement.winners,
+        )?;
+    }
+
+    let withdrawal = withdrawal_msgs(
+        &settlement.withdrawal_address,
+        &settlement.split,
+        settlement.proceeds,
+        &settlement.denom,
+    );
+
+    // Notify the hooks
+    let hooks = BidHookMsg::PeriodSettled {
+        period: settlement.period_id,
+        winners: settlement.winners,
+    }
+    .into_submsgs(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "release_proceeds")
+        .add_attribute("withdrawn", settlement.proceeds.to_string())
+        .add_messages(withdrawal)
+        .add_submessages(hooks))
+}
+
+fn execute_veto_settlement(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let settlement = PENDING_SETTLEMENT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingSettlement {})?;
+
+    // Method is reserved to the guardian, during the dispute window
+    if info.sender != settlement.guardian {
+        return Err(ContractError::Unauthorized {});
+    }
+    if env.block.time >= settlement.releasable_at {
+        return Err(ContractError::DisputeWindowClosed {});
+    }
+
+    PENDING_SETTLEMENT.remove(deps.storage);
+
+    // Refund all accepted bids
+    let msgs: Vec<BankMsg> = settlement
+        .winners
+        .iter()
+        .flat_map(|bid| bid.refunds(&settlement.refund_to))
+        .map(|refund| payout_msg(&refund, &settlement.denom))
+        .collect();
+
+    // Notify the hooks of each refunded bid
+    let hooks = withdrawn_hooks(deps.storage, settlement.period_id, &settlement.winners)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "veto_settlement")
+        .add_attribute("refunded", settlement.proceeds.to_string())
         .add_messages(msgs)
-        .add_message(withdrawal))
+        .add_submessages(hooks))
 }
 
-fn execute_bid(
+fn update_admins(
+    deps: DepsMut,
+    admins: Vec<Addr>,
+    threshold: u64,
+) -> Result<Response, ContractError> {
+    for admin in &admins {
+        deps.api.addr_validate(admin.as_str())?;
+    }
+    let admins = AdminList { admins, threshold };
+    validate_admin_list(&admins)?;
+    ADMINS.save(deps.storage, &admins)?;
+    // A pending transfer of a replaced admin's seat, or to a new admin, must not survive the update
+    PENDING_ADMIN_TRANSFER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "update_admins")
+        .add_attribute("threshold", threshold.to_string()))
+}
+
+/// Verifies that no admin is listed twice, and that the threshold can be reached
+fn validate_admin_list(admins: &AdminList) -> Result<(), ContractError> {
+    if let Some(admin) = admins.duplicate_admin() {
+        return Err(ContractError::DuplicateAdmin {
+            address: admin.to_string(),
+        });
+    }
+    if !admins.is_valid_threshold() {
+        return Err(ContractError::InvalidThreshold {});
+    }
+    Ok(())
+}
+
+fn execute_update_bidding_period(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    address: Option<Addr>,
+    config: BiddingPeriod,
 ) -> Result<Response, ContractError> {
-    // There must be a current bidding period for a user to submit a bid
-    let bidding_period = BIDDING_PERIOD.load(deps.storage)?;
+    // Method is privileged
+    let action = ProposalAction::UpdateBiddingPeriod { config };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn update_bidding_period(
+    deps: DepsMut,
+    env: Env,
+    config: BiddingPeriod,
+) -> Result<Response, ContractError> {
+    let current = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
 
-    // Verify that the bidding period is not expired
-    if env.block.time >= bidding_period.expires_at {
+    // An expired bidding period cannot be reopened
+    if current.expires_at.is_expired(&env.block) {
         return Err(ContractError::BiddingPeriodExpired {});
     }
 
-    // Get the amount of tokens paid
-    let amount_paid = must_pay(&info, &bidding_period.denom)?;
+    // Verify that the fields bidders rely on are unchanged
+    let immutable_fields = [
+        ("denom", config.denom != current.denom),
+        ("starts_at", config.starts_at != current.starts_at),
+        (
+            "max_bid_per_address",
+            config.max_bid_per_address != current.max_bid_per_address,
+        ),
+        ("max_bidders", config.max_bidders != current.max_bidders),
+        (
+            "min_increment",
+            config.min_increment != current.min_increment,
+        ),
+        ("refund_to", config.refund_to != current.refund_to),
+        ("group", config.group != current.group),
+        ("attestor", config.attestor != current.attestor),
+        ("dispute", config.dispute != current.dispute),
+        (
+            "receipt_collection",
+            config.receipt_collection != current.receipt_collection,
+        ),
+        (
+            "transferable_bids",
+            config.transferable_bids != current.transferable_bids,
+        ),
+        (
+            "metadata.terms_hash",
+            period_terms_hash(&config) != period_terms_hash(&current),
+        ),
+    ];
+    if let Some((field, _)) = immutable_fields.iter().find(|(_, changed)| *changed) {
+        return Err(ContractError::ImmutableField {
+            field: field.to_string(),
+        });
+    }
 
-    // Verify that the amount paid is over the minimum bid amount
-    if amount_paid < bidding_period.minimum_bid {
-        return Err(ContractError::Payment(cw_utils::PaymentError::NoFunds {}));
+    if let Some(metadata) = &config.metadata {
+        validate_metadata(metadata)?;
     }
 
-    let address = match address {
-        Some(address) => address,
-        None => info.sender,
-    };
+    // Verify that the expiry is extended, and neither in the past nor never
+    if matches!(config.expires_at, Expiration::Never {}) {
+        return Err(ContractError::InvalidExpiry {});
+    }
+    match config.expires_at.partial_cmp(&current.expires_at) {
+        Some(Ordering::Less) => return Err(ContractError::ExpiryShortened {}),
+        None => return Err(ContractError::InvalidExpiry {}),
+        _ => {}
+    }
+    if config.expires_at.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiry {});
+    }
 
-    // If the bid already exists, add on the amount sent
-    // If not, create a new bid for the user
-    let bid = BIDS.may_load(deps.storage, &address)?;
-    match bid {
-        Some(_) => BIDS.update(deps.storage, &address, |bid| match bid {
-            Some(bid) => Ok(Bid {
-                bidder: address.clone(),
-                amount: bid.amount + amount_paid,
-            }),
-            None => Err(ContractError::NotFound {}),
-        }),
-        None => {
-            let bid = Bid {
-                bidder: address.clone(),
-                amount: amount_paid,
-            };
-            let res = BIDS.save(deps.storage, &address, &bid);
-            match res {
-                Ok(_) => Ok(bid),
-                Err(err) => Err(ContractError::Std(err)),
+    // Verify that the number of accepted bids is not lowered
+    if config.accepted_bidders < current.accepted_bidders {
+        return Err(ContractError::AcceptedBiddersLowered {});
+    }
+
+    // Verify that no existing bid falls below the minimum bid
+    if config.minimum_bid > current.minimum_bid {
+        let lowest_bid = BID_RANKING
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .transpose()?;
+        if let Some((amount, _)) = lowest_bid {
+            if Uint128::new(amount) < config.minimum_bid {
+                return Err(ContractError::MinimumAboveBids {
+                    minimum: config.minimum_bid,
+                });
             }
         }
-    }?;
+    }
 
-    // Verify that the bid was created and get the "new amount"
-    let bid = BIDS.load(deps.storage, &address)?;
+    BIDDING_PERIOD.save(deps.storage, &config)?;
+
+    // The cut-off bid depends on the number of accepted bids
+    refresh_cutoff(deps.storage, config.accepted_bidders)?;
 
     Ok(Response::new()
-        .add_attribute("method", "bid")
-        .add_attribute("address", address.to_string())
-        .add_attribute("amount", amount_paid.to_string())
-        .add_attribute("new_amount", bid.amount.to_string()))
+        .add_attribute("method", "update_bidding_period")
+        .add_attribute("bidding_period_name", config.name)
+        .add_attribute("bidding_period_expires_at", config.expires_at.to_string())
+        .add_attribute(
+            "bidding_period_accepted_bidders",
+            config.accepted_bidders.to_string(),
+        ))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::BiddingPeriod {} => to_binary(&query_bidding_period(deps)?),
-        QueryMsg::Bids {} => to_binary(&query_bids(deps)?),
-        QueryMsg::Bid { address } => to_binary(&query_bid(deps, address)?),
+/// Verifies that the metadata fields are within their length limits, and that
+/// the terms hash is a hex-encoded SHA-256 hash
+fn validate_metadata(metadata: &PeriodMetadata) -> Result<(), ContractError> {
+    let too_long = |field: &str, max: usize| ContractError::MetadataTooLong {
+        field: field.to_string(),
+        max,
+    };
+    // Limits are in characters rather than bytes, so that non-ASCII text is not penalized
+    let length = |text: &String| text.chars().count();
+
+    if metadata.image.as_ref().map_or(0, length) > MAX_URI_LENGTH {
+        return Err(too_long("image", MAX_URI_LENGTH));
+    }
+    if metadata.links.len() > MAX_LINKS {
+        return Err(too_long("links", MAX_LINKS));
+    }
+    if metadata
+        .links
+        .iter()
+        .any(|link| length(link) > MAX_URI_LENGTH)
+    {
+        return Err(too_long("link", MAX_URI_LENGTH));
+    }
+    if metadata.category.as_ref().map_or(0, length) > MAX_CATEGORY_LENGTH {
+        return Err(too_long("category", MAX_CATEGORY_LENGTH));
+    }
+    if metadata.tags.len() > MAX_TAGS {
+        return Err(too_long("tags", MAX_TAGS));
+    }
+    if metadata.tags.iter().any(|tag| length(tag) > MAX_TAG_LENGTH) {
+        return Err(too_long("tag", MAX_TAG_LENGTH));
+    }
+    if let Some(terms_hash) = &metadata.terms_hash {
+        let is_hex = terms_hash
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+        if terms_hash.len() != 64 || !is_hex {
+            return Err(ContractError::InvalidTermsHash {});
+        }
     }
+    Ok(())
 }
 
-fn query_bidding_period(deps: Deps) -> StdResult<BiddingPeriodResponse> {
-    let bidding_period = BIDDING_PERIOD.may_load(deps.storage)?;
-    Ok(BiddingPeriodResponse { bidding_period })
+/// Hash of the bidding period's terms of sale, if it has any
+fn period_terms_hash(bidding_period: &BiddingPeriod) -> Option<&String> {
+    bidding_period
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.terms_hash.as_ref())
 }
 
-fn query_bids(deps: Deps) -> StdResult<BidsResponse> {
+fn execute_create_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    template: BiddingPeriod,
+    interval: Duration,
+    count: u64,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::CreateSchedule {
+        template,
+        interval,
+        count,
+    };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn create_schedule(
+    deps: DepsMut,
+    template: BiddingPeriod,
+    interval: Duration,
+    count: u64,
+) -> Result<Response, ContractError> {
+    if matches!(interval, Duration::Height(0) | Duration::Time(0)) || count == 0 {
+        return Err(ContractError::InvalidSchedule {});
+    }
+    // The rest of the template is verified as each bidding period starts
+    if template.accepted_bidders < 1 {
+        return Err(ContractError::InvalidAcceptedBidders {});
+    }
+
+    SCHEDULE.save(
+        deps.storage,
+        &Schedule {
+            template,
+            interval,
+            count,
+            started: 0,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_schedule")
+        .add_attribute("interval", interval.to_string())
+        .add_attribute("count", count.to_string()))
+}
+
+fn execute_cancel_schedule(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !has_role(deps.as_ref(), info.sender.as_ref(), &Role::Operator)? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    if SCHEDULE.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoSchedule {});
+    }
+    SCHEDULE.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("method", "cancel_schedule"))
+}
+
+fn execute_start_scheduled_bidding(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Scheduled bidding periods cannot start while the contract is paused
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    let mut schedule = SCHEDULE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoSchedule {})?;
+    let res = start_bidding(deps.branch(), env.clone(), schedule.next_period(&env.block))?;
+
+    // Remove the schedule once its last bidding period has started
+    schedule.started += 1;
+    if schedule.started < schedule.count {
+        SCHEDULE.save(deps.storage, &schedule)?;
+    } else {
+        SCHEDULE.remove(deps.storage);
+    }
+
+    Ok(res.add_attribute("scheduled", schedule.started.to_string()))
+}
+
+fn execute_cancel_bidding(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !has_role(deps.as_ref(), info.sender.as_ref(), &Role::Operator)? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    cancel_bidding(deps)
+}
+
+/// Removes the bidding period, refunding all bids
+fn cancel_bidding(deps: DepsMut) -> Result<Response, ContractError> {
+    // Verify that there is a bidding period to cancel, and remove it
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    BIDDING_PERIOD.remove(deps.storage);
+
+    // Refund all bids
     let bids = BIDS
-        .keys(deps.storage, None, None, Order::Descending)
-        .map(|res| res.map(|addr| BIDS.load(deps.storage, &addr).unwrap()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, bid)| bid))
         .collect::<StdResult<Vec<_>>>()?;
-    Ok(BidsResponse { bids })
+    let msgs: Vec<BankMsg> = bids
+        .iter()
+        .flat_map(|bid| refund_msgs(bid, &bidding_period))
+        .collect();
+    clear_bids(deps.storage);
+
+    // Notify the hooks of each refunded bid
+    let hooks = withdrawn_hooks(deps.storage, PERIOD_ID.load(deps.storage)?, &bids)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_bidding")
+        .add_messages(msgs)
+        .add_submessages(hooks))
+}
+
+/// Hook submessages notifying that each of `bids` was refunded, whose failures are
+/// ignored so that hooks cannot block the refunds
+fn withdrawn_hooks(storage: &dyn Storage, period: u64, bids: &[Bid]) -> StdResult<Vec<SubMsg>> {
+    let mut hooks = vec![];
+    for bid in bids {
+        let msg = BidHookMsg::BidWithdrawn {
+            period,
+            bidder: bid.bidder.clone(),
+            amount: bid.amount,
+        };
+        hooks.extend(msg.into_ignored_submsgs(storage)?);
+    }
+    Ok(hooks)
+}
+
+fn execute_grant_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    role: Role,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::GrantRole { address, role };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn execute_revoke_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    role: Role,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::RevokeRole { address, role };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn execute_set_treasury(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Option<Addr>,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::SetTreasury { address };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn execute_set_proceeds_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    split: Vec<ProceedsShare>,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::SetProceedsSplit { split };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+fn execute_propose_admin_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_admin: Addr,
+    expiry: Expiration,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    deps.api.addr_validate(new_admin.as_str())?;
+    if can_execute(deps.as_ref(), new_admin.as_ref())? {
+        return Err(ContractError::AlreadyAdmin {
+            address: new_admin.to_string(),
+        });
+    }
+    if expiry.is_expired(&env.block) {
+        return Err(ContractError::AdminTransferExpired {});
+    }
+
+    // Admins can replace their own transfer, but not another admin's until it expires
+    if let Some(transfer) = PENDING_ADMIN_TRANSFER.may_load(deps.storage)? {
+        if transfer.from != info.sender && !transfer.expiry.is_expired(&env.block) {
+            return Err(ContractError::AdminTransferPending {});
+        }
+    }
+
+    PENDING_ADMIN_TRANSFER.save(
+        deps.storage,
+        &AdminTransfer {
+            from: info.sender.clone(),
+            to: new_admin.clone(),
+            expiry,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "propose_admin_transfer")
+        .add_attribute("from", info.sender.to_string())
+        .add_attribute("to", new_admin.to_string()))
+}
+
+fn execute_accept_admin_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let transfer = PENDING_ADMIN_TRANSFER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingAdminTransfer {})?;
+
+    // Method is reserved to the nominee
+    if info.sender != transfer.to {
+        return Err(ContractError::Unauthorized {});
+    }
+    if transfer.expiry.is_expired(&env.block) {
+        return Err(ContractError::AdminTransferExpired {});
+    }
+    // The nominee may have been made an admin since the transfer was proposed
+    if can_execute(deps.as_ref(), transfer.to.as_ref())? {
+        return Err(ContractError::AlreadyAdmin {
+            address: transfer.to.to_string(),
+        });
+    }
+
+    // The nominee takes over the seat of the admin, if they still hold one
+    let mut admins = ADMINS.load(deps.storage)?;
+    let seat = admins
+        .admins
+        .iter_mut()
+        .find(|admin| **admin == transfer.from)
+        .ok_or_else(|| ContractError::AdminSeatVacated {
+            address: transfer.from.to_string(),
+        })?;
+    *seat = transfer.to.clone();
+    ADMINS.save(deps.storage, &admins)?;
+    PENDING_ADMIN_TRANSFER.remove(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("method", "accept_admin_transfer")
+        .add_attribute("from", transfer.from.to_string())
+        .add_attribute("to", transfer.to.to_string()))
+}
+
+fn execute_cancel_admin_transfer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    if PENDING_ADMIN_TRANSFER.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingAdminTransfer {});
+    }
+    PENDING_ADMIN_TRANSFER.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("method", "cancel_admin_transfer"))
+}
+
+fn grant_role(deps: DepsMut, address: Addr, role: Role) -> Result<Response, ContractError> {
+    // Owners are the admins, and are changed through `update_admins`
+    if role == Role::Owner {
+        return Err(ContractError::OwnerNotGrantable {});
+    }
+    deps.api.addr_validate(address.as_str())?;
+
+    let mut roles = ROLES.may_load(deps.storage, &address)?.unwrap_or_default();
+    if !roles.contains(&role) {
+        roles.push(role.clone());
+    }
+    ROLES.save(deps.storage, &address, &roles)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "grant_role")
+        .add_attribute("address", address.to_string())
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+fn execute_update_denylist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    add: Vec<Addr>,
+    remove: Vec<Addr>,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    let action = ProposalAction::UpdateDenylist { add, remove };
+    can_execute_directly(deps.as_ref(), &info.sender, &action)?;
+
+    execute_action(deps, env, info.sender, action)
+}
+
+/// Denylisted addresses keep the bid they hold, if any, until it is disqualified
+fn update_denylist(
+    deps: DepsMut,
+    add: Vec<Addr>,
+    remove: Vec<Addr>,
+) -> Result<Response, ContractError> {
+    for address in &add {
+        deps.api.addr_validate(address.as_str())?;
+        DENYLIST.save(deps.storage, address, &Empty {})?;
+    }
+    for address in &remove {
+        DENYLIST.remove(deps.storage, address);
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "update_denylist")
+        .add_attribute("added", add.len().to_string())
+        .add_attribute("removed", remove.len().to_string()))
+}
+
+fn revoke_role(deps: DepsMut, address: Addr, role: Role) -> Result<Response, ContractError> {
+    let mut roles = ROLES.may_load(deps.storage, &address)?.unwrap_or_default();
+    roles.retain(|r| r != &role);
+    if roles.is_empty() {
+        ROLES.remove(deps.storage, &address);
+    } else {
+        ROLES.save(deps.storage, &address, &roles)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_role")
+        .add_attribute("address", address.to_string())
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+fn set_treasury(deps: DepsMut, address: Option<Addr>) -> Result<Response, ContractError> {
+    match &address {
+        Some(address) => {
+            deps.api.addr_validate(address.as_str())?;
+            TREASURY.save(deps.storage, address)?;
+        }
+        None => TREASURY.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "set_treasury")
+        .add_attribute(
+            "treasury",
+            address.map_or_else(|| "null".into(), |a| a.to_string()),
+        ))
+}
+
+fn set_proceeds_split(deps: DepsMut, split: Vec<ProceedsShare>) -> Result<Response, ContractError> {
+    validate_proceeds_split(deps.as_ref(), &split)?;
+    PROCEEDS_SPLIT.save(deps.storage, &split)?;
+
+    let total: u64 = split.iter().map(|share| share.bps).sum();
+    Ok(Response::new()
+        .add_attribute("method", "set_proceeds_split")
+        .add_attribute("shares", split.len().to_string())
+        .add_attribute("total_bps", total.to_string()))
+}
+
+/// Verifies that the split has at most `MAX_SPLIT_SHARES` positive shares of distinct
+/// valid addresses, adding up to at most `TOTAL_BPS`
+fn validate_proceeds_split(deps: Deps, split: &[ProceedsShare]) -> Result<(), ContractError> {
+    if split.len() > MAX_SPLIT_SHARES {
+        return Err(ContractError::InvalidProceedsSplit {});
+    }
+    let mut total = 0u64;
+    for (i, share) in split.iter().enumerate() {
+        deps.api.addr_validate(share.address.as_str())?;
+        let duplicate = split[..i]
+            .iter()
+            .any(|other| other.address == share.address);
+        if share.bps == 0 || duplicate {
+            return Err(ContractError::InvalidProceedsSplit {});
+        }
+        total = total.saturating_add(share.bps);
+    }
+    if total > TOTAL_BPS {
+        return Err(ContractError::InvalidProceedsSplit {});
+    }
+    Ok(())
+}
+
+/// Executes a privileged action once it has been authorized
+fn execute_action(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    action: ProposalAction,
+) -> Result<Response, ContractError> {
+    match action {
+        ProposalAction::StartBidding { config } => start_bidding(deps, env, config),
+        ProposalAction::EndBidding {
+            accepted_bids,
+            withdrawal_address,
+        } => end_bidding(deps, env, sender, accepted_bids, withdrawal_address),
+        ProposalAction::UpdateAdmins { admins, threshold } => {
+            update_admins(deps, admins, threshold)
+        }
+        ProposalAction::GrantRole { address, role } => grant_role(deps, address, role),
+        ProposalAction::RevokeRole { address, role } => revoke_role(deps, address, role),
+        ProposalAction::UpdateDenylist { add, remove } => update_denylist(deps, add, remove),
+        ProposalAction::SetTreasury { address } => set_treasury(deps, address),
+        ProposalAction::SetProceedsSplit { split } => set_proceeds_split(deps, split),
+        ProposalAction::UpdateBiddingPeriod { config } => update_bidding_period(deps, env, config),
+        ProposalAction::CreateSchedule {
+            template,
+            interval,
+            count,
+        } => create_schedule(deps, template, interval, count),
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: ProposalAction,
+    expires: Expiration,
+) -> Result<Response, ContractError> {
+    // Method is reserved to holders of the roles the action requires
+    if !has_roles(deps.as_ref(), &info.sender, &action.required_roles())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::ProposalExpired {});
+    }
+
+    let id = PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    // The proposer approves their own proposal
+    let proposal = Proposal {
+        id,
+        proposer: info.sender.clone(),
+        action,
+        approvals: vec![info.sender],
+        expires,
+        executed: false,
+    };
+
+    execute_if_approved(deps, env, proposal, "propose")
+}
+
+fn execute_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut proposal = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::ProposalNotFound { proposal_id })?;
+
+    // Method is reserved to holders of the roles the action requires
+    if !has_roles(
+        deps.as_ref(),
+        &info.sender,
+        &proposal.action.required_roles(),
+    )? {
+        return Err(ContractError::Unauthorized {});
+    };
+    if proposal.executed {
+        return Err(ContractError::ProposalExecuted {});
+    }
+    if proposal.expires.is_expired(&env.block) {
+        return Err(ContractError::ProposalExpired {});
+    }
+    if proposal.approvals.contains(&info.sender) {
+        return Err(ContractError::AlreadyApproved {});
+    }
+    proposal.approvals.push(info.sender);
+
+    execute_if_approved(deps, env, proposal, "approve")
+}
+
+/// Saves the proposal, executing its action first if the approval threshold is reached
+/// Only approvals of current admins are counted, as the threshold is over the admins
+fn execute_if_approved(
+    mut deps: DepsMut,
+    env: Env,
+    mut proposal: Proposal,
+    method: &str,
+) -> Result<Response, ContractError> {
+    let admins = ADMINS.load(deps.storage)?;
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approval| admins.is_admin(approval.as_str()))
+        .count() as u64;
+    let threshold = admins.threshold;
+
+    let mut res = Response::new()
+        .add_attribute("method", method)
+        .add_attribute("proposal_id", proposal.id.to_string())
+        .add_attribute("approvals", approvals.to_string());
+
+    if approvals >= threshold {
+        let action_res = execute_action(
+            deps.branch(),
+            env,
+            proposal.proposer.clone(),
+            proposal.action.clone(),
+        )?;
+        proposal.executed = true;
+        res = res
+            .add_attributes(action_res.attributes)
+            .add_submessages(action_res.messages);
+    }
+    PROPOSALS.save(deps.storage, proposal.id, &proposal)?;
+
+    Ok(res.add_attribute("executed", proposal.executed.to_string()))
+}
+
+fn execute_disqualify_bid(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: Addr,
+    reason: String,
+    denylist: bool,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !has_role(deps.as_ref(), info.sender.as_ref(), &Role::Operator)? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    // Verify that there is a bid to disqualify, and remove it
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    let bid = remove_bid(deps.storage, &address)?.ok_or_else(|| ContractError::UnknownBidder {
+        address: address.to_string(),
+    })?;
+
+    // Refund the bidder
+    let refunds = refund_msgs(&bid, &bidding_period);
+
+    if denylist {
+        DENYLIST.save(deps.storage, &address, &Empty {})?;
+    }
+
+    // Notify the hooks
+    let hooks = BidHookMsg::BidWithdrawn {
+        period: PERIOD_ID.load(deps.storage)?,
+        bidder: address.clone(),
+        amount: bid.amount,
+    }
+    .into_submsgs(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "disqualify_bid")
+        .add_attribute("address", address.to_string())
+        .add_attribute("reason", reason)
+        .add_attribute("denylisted", denylist.to_string())
+        .add_attribute("refunded", bid.amount.to_string())
+        .add_messages(refunds)
+        .add_submessages(hooks))
+}
+
+fn execute_claim_receipt(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    period_id: u64,
+) -> Result<Response, ContractError> {
+    let mut receipt = RECEIPTS
+        .may_load(deps.storage, (period_id, &info.sender))?
+        .ok_or(ContractError::NoReceipt { period_id })?;
+    if receipt.claimed {
+        return Err(ContractError::ReceiptAlreadyClaimed { period_id });
+    }
+    receipt.claimed = true;
+    RECEIPTS.save(deps.storage, (period_id, &info.sender), &receipt)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "claim_receipt")
+        .add_attribute("period_id", period_id.to_string())
+        .add_attribute("address", info.sender.to_string())
+        .add_attribute("rank", receipt.rank.to_string())
+        .add_message(receipt.mint_msg(&info.sender)?))
+}
+
+fn execute_emergency_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Option<Addr>,
+) -> Result<Response, ContractError> {
+    // Only the bidder or their operators can withdraw the bid
+    let address = address.unwrap_or_else(|| info.sender.clone());
+    if !can_manage_bid(deps.storage, &env.block, &address, &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Verify that the contract has been paused for longer than the emergency withdrawal delay
+    let paused_at = PAUSED_AT
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NotPaused {})?;
+    let config = CONFIG.load(deps.storage)?;
+    let available_at = paused_at.plus_seconds(config.emergency_withdraw_delay);
+    if env.block.time < available_at {
+        return Err(ContractError::EmergencyWithdrawUnavailable { available_at });
+    }
+
+    // Remove the bid and refund it
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    let bid = remove_bid(deps.storage, &address)?.ok_or_else(|| ContractError::UnknownBidder {
+        address: address.to_string(),
+    })?;
+    let refunds = refund_msgs(&bid, &bidding_period);
+
+    // Notify the hooks, which cannot block the withdrawal
+    let hooks = BidHookMsg::BidWithdrawn {
+        period: PERIOD_ID.load(deps.storage)?,
+        bidder: address.clone(),
+        amount: bid.amount,
+    }
+    .into_ignored_submsgs(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "emergency_withdraw")
+        .add_attribute("address", address.to_string())
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("withdrawn", bid.amount.to_string())
+        .add_messages(refunds)
+        .add_submessages(hooks))
+}
+
+fn execute_transfer_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from: Option<Addr>,
+    to: Addr,
+) -> Result<Response, ContractError> {
+    // Bids cannot be transferred while the contract is paused
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    if !bidding_period.transferable_bids {
+        return Err(ContractError::BidTransfersDisabled {});
+    }
+    if bidding_period.expires_at.is_expired(&env.block) {
+        return Err(ContractError::BiddingPeriodExpired {});
+    }
+    // The recipient's attestation cannot be verified
+    if bidding_period.attestor.is_some() {
+        return Err(ContractError::AttestationRequired {});
+    }
+
+    // Only the bidder or their operators can transfer the bid
+    let from = from.unwrap_or_else(|| info.sender.clone());
+    if !can_manage_bid(deps.storage, &env.block, &from, &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let to = deps.api.addr_validate(to.as_str())?;
+    if to == from {
+        return Err(ContractError::CannotTransferToSelf {});
+    }
+
+    // Verify that neither the bidder nor the recipient are denylisted
+    for addr in [&from, &to] {
+        if DENYLIST.has(deps.storage, addr) {
+            return Err(ContractError::Denylisted {
+                address: addr.to_string(),
+            });
+        }
+    }
+
+    let bid = BIDS
+        .may_load(deps.storage, &from)?
+        .ok_or_else(|| ContractError::UnknownBidder {
+            address: from.to_string(),
+        })?;
+    let amount = bid.amount;
+
+    // Merge the bid into the recipient's bid, if they have one
+    let mut merged = BIDS.may_load(deps.storage, &to)?.unwrap_or_else(|| Bid {
+        bidder: to.clone(),
+        amount: Uint128::zero(),
+        payers: vec![],
+        first_bid_at: bid.first_bid_at,
+        last_bid_at: bid.last_bid_at,
+        terms_hash: None,
+    });
+    merged.merge(bid);
+    validate_holder(deps.as_ref(), &bidding_period, &to, merged.amount)?;
+
+    remove_bid(deps.storage, &from)?;
+    save_bid(deps.storage, &merged, bidding_period.accepted_bidders)?;
+
+    // Notify the hooks
+    let hooks = BidHookMsg::BidTransferred {
+        period: PERIOD_ID.load(deps.storage)?,
+        from: from.clone(),
+        to: to.clone(),
+        amount,
+        total: merged.amount,
+    }
+    .into_submsgs(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_bid")
+        .add_attribute("from", from.to_string())
+        .add_attribute("sender", info.sender.to_string())
+        .add_attribute("to", to.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("new_amount", merged.amount.to_string())
+        .add_submessages(hooks))
+}
+
+fn execute_approve_operator(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operator: Addr,
+    expires: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let operator = deps.api.addr_validate(operator.as_str())?;
+    if operator == info.sender {
+        return Err(ContractError::CannotApproveSelf {});
+    }
+
+    // Verify that the approval is not already expired
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiry {});
+    }
+    OPERATORS.save(deps.storage, (&info.sender, &operator), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve_operator")
+        .add_attribute("owner", info.sender.to_string())
+        .add_attribute("operator", operator.to_string())
+        .add_attribute("expires", expires.to_string()))
+}
+
+fn execute_revoke_operator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operator: Addr,
+) -> Result<Response, ContractError> {
+    if !OPERATORS.has(deps.storage, (&info.sender, &operator)) {
+        return Err(ContractError::OperatorNotApproved {
+            operator: operator.to_string(),
+        });
+    }
+    OPERATORS.remove(deps.storage, (&info.sender, &operator));
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_operator")
+        .add_attribute("owner", info.sender.to_string())
+        .add_attribute("operator", operator.to_string()))
+}
+
+/// Messages refunding a bid according to the bidding period's refund target
+fn refund_msgs(bid: &Bid, bidding_period: &BiddingPeriod) -> Vec<BankMsg> {
+    bid.refunds(&bidding_period.refund_to)
+        .iter()
+        .map(|refund| payout_msg(refund, &bidding_period.denom))
+        .collect()
+}
+
+fn payout_msg(payout: &Payout, denom: &str) -> BankMsg {
+    BankMsg::Send {
+        to_address: payout.address.to_string(),
+        amount: vec![coin(payout.amount.u128(), denom)],
+    }
+}
+
+/// Messages paying each share of `split` its part of the proceeds and the rest to `address`,
+/// skipping empty amounts as the bank module rejects empty transfers
+fn withdrawal_msgs(
+    address: &Addr,
+    split: &[ProceedsShare],
+    proceeds: Uint128,
+    denom: &str,
+) -> Vec<BankMsg> {
+    let mut payouts: Vec<Payout> = split
+        .iter()
+        .map(|share| Payout {
+            address: share.address.clone(),
+            amount: proceeds.multiply_ratio(share.bps, TOTAL_BPS),
+        })
+        .collect();
+    let paid: Uint128 = payouts.iter().map(|payout| payout.amount).sum();
+    payouts.push(Payout {
+        address: address.clone(),
+        amount: proceeds - paid,
+    });
+
+    payouts
+        .iter()
+        .filter(|payout| !payout.amount.is_zero())
+        .map(|payout| payout_msg(payout, denom))
+        .collect()
+}
+
+/// Outcome of ending a bidding period
+struct Settlement {
+    /// Accepted bids
+    accepted: Vec<Bid>,
+    /// Refunds of the rejected bids
+    refunds: Vec<Payout>,
+    /// Total amount of the accepted bids
+    proceeds: Uint128,
+}
+
+/// Works out the outcome of ending the bidding period by accepting `accepted_bids`,
+/// without modifying any state
+fn compute_settlement(
+    deps: Deps,
+    bidding_period: &BiddingPeriod,
+    accepted_bids: &[Addr],
+    withdrawal_address: Option<&Addr>,
+) -> Result<Settlement, ContractError> {
+    // The bidding period cannot be settled while the contract is paused
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    // Verify that the funds can be withdrawn to the withdrawal address
+    if let Some(address) = withdrawal_address {
+        deps.api.addr_validate(address.as_str()).map_err(|_| {
+            ContractError::InvalidWithdrawalAddress {
+                address: address.to_string(),
+            }
+        })?;
+    }
+
+    // Keep the funds of all accepted bids
+    let mut accepted: Vec<Bid> = vec![];
+    let mut proceeds = Uint128::zero();
+    for address in accepted_bids {
+        if accepted.iter().any(|bid| &bid.bidder == address) {
+            return Err(ContractError::DuplicateAcceptedBid {
+                address: address.to_string(),
+            });
+        }
+        let bid =
+            BIDS.may_load(deps.storage, address)?
+                .ok_or_else(|| ContractError::UnknownBidder {
+                    address: address.to_string(),
+                })?;
+        proceeds += bid.amount;
+        accepted.push(bid);
+    }
+
+    // Verify that the list of accepted bids is no longer than the config dictates
+    if accepted.len() as u64 > bidding_period.accepted_bidders {
+        return Err(ContractError::TooManyAcceptedBids {
+            max: bidding_period.accepted_bidders,
+        });
+    }
+
+    // Reimburse all rejected bids
+    let mut refunds: Vec<Payout> = vec![];
+    for res in BIDS.range(deps.storage, None, None, Order::Ascending) {
+        let (address, bid) = res?;
+        if !accepted_bids.contains(&address) {
+            refunds.extend(bid.refunds(&bidding_period.refund_to));
+        }
+    }
+
+    Ok(Settlement {
+        accepted,
+        refunds,
+        proceeds,
+    })
+}
+
+fn execute_add_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: Addr,
+    on_error: HookFailure,
+    gas_limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    deps.api.addr_validate(address.as_str())?;
+    if HOOKS.has(deps.storage, &address) {
+        return Err(ContractError::HookAlreadyRegistered {
+            address: address.to_string(),
+        });
+    }
+    let config = HookConfig {
+        on_error,
+        gas_limit: gas_limit.unwrap_or(DEFAULT_HOOK_GAS_LIMIT),
+    };
+    HOOKS.save(deps.storage, &address, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_hook")
+        .add_attribute("address", address.to_string()))
+}
+
+fn execute_remove_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    if !HOOKS.has(deps.storage, &address) {
+        return Err(ContractError::HookNotRegistered {
+            address: address.to_string(),
+        });
+    }
+    HOOKS.remove(deps.storage, &address);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_hook")
+        .add_attribute("address", address.to_string()))
+}
+
+fn execute_pause(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    pause(deps, env)
+}
+
+fn pause(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+    PAUSED_AT.save(deps.storage, &env.block.time)?;
+
+    Ok(Response::new().add_attribute("method", "pause"))
+}
+
+fn execute_unpause(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Method is privileged
+    if !can_execute(deps.as_ref(), info.sender.as_ref())? {
+        return Err(ContractError::Unauthorized {});
+    };
+
+    unpause(deps)
+}
+
+fn unpause(deps: DepsMut) -> Result<Response, ContractError> {
+    if !is_paused(deps.storage)? {
+        return Err(ContractError::NotPaused {});
+    }
+    PAUSED_AT.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("method", "unpause"))
+}
+
+fn execute_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Option<Addr>,
+    attestation: Option<Attestation>,
+    terms_hash: Option<String>,
+) -> Result<Response, ContractError> {
+    // There must be a current bidding period for a user to submit a bid
+    let bidding_period = BIDDING_PERIOD
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoBiddingPeriod {})?;
+    let period_id = PERIOD_ID.load(deps.storage)?;
+
+    // Get the amount of tokens paid
+    let amount_paid = must_pay(&info, &bidding_period.denom)?;
+
+    let address = match address {
+        Some(address) => address,
+        None => info.sender.clone(),
+    };
+
+    // If the bid already exists, add on the amount sent
+    // If not, create a new bid for the user
+    let bid = BIDS.may_load(deps.storage, &address)?;
+    let new_amount = validate_bid(
+        deps.as_ref(),
+        &env,
+        &bidding_period,
+        &info.sender,
+        &address,
+        amount_paid,
+        bid.as_ref(),
+    )?;
+
+    // If the bidding period has terms of sale, the sender must acknowledge them
+    let terms = period_terms_hash(&bidding_period).cloned();
+    if let Some(terms) = &terms {
+        if terms_hash.as_ref() != Some(terms) {
+            return Err(ContractError::TermsNotAccepted {
+                terms_hash: terms.clone(),
+            });
+        }
+    }
+
+    // If the bidding period has an attestor, the bidder must hold a valid attestation
+    if let Some(attestor) = &bidding_period.attestor {
+        let attestation = attestation.ok_or(ContractError::AttestationRequired {})?;
+
+        if attestation.expiry <= env.block.time {
+            return Err(ContractError::AttestationExpired {});
+        }
+        if new_amount > attestation.max_amount {
+            return Err(ContractError::AttestationLimitExceeded {
+                max_amount: attestation.max_amount,
+            });
+        }
+        if USED_NONCES.has(deps.storage, (&address, attestation.nonce)) {
+            return Err(ContractError::NonceAlreadyUsed {
+                nonce: attestation.nonce,
+            });
+        }
+
+        let payload = AttestationPayload {
+            contract: env.contract.address.clone(),
+            period_id,
+            bidder: address.clone(),
+            max_amount: attestation.max_amount,
+            expiry: attestation.expiry,
+            nonce: attestation.nonce,
+        };
+        if !attestor.verify(deps.api, &payload, &attestation.signature)? {
+            return Err(ContractError::InvalidAttestation {});
+        }
+
+        USED_NONCES.save(deps.storage, (&address, attestation.nonce), &Empty {})?;
+    }
+
+    let mut bid = bid.unwrap_or_else(|| Bid {
+        bidder: address.clone(),
+        amount: Uint128::zero(),
+        payers: vec![],
+        first_bid_at: env.block.time,
+        last_bid_at: env.block.time,
+        terms_hash: None,
+    });
+    bid.add_payment(&info.sender, amount_paid);
+    bid.last_bid_at = env.block.time;
+    bid.terms_hash = terms;
+    save_bid(deps.storage, &bid, bidding_period.accepted_bidders)?;
+
+    // Log the contribution in the bidder's history
+    append_contribution(
+        deps.storage,
+        &address,
+        Contribution {
+            index: 0,
+            period_id,
+            payer: info.sender.clone(),
+            amount: amount_paid,
+            time: env.block.time,
+            height: env.block.height,
+        },
+    )?;
+
+    // Notify the hooks
+    let hooks = BidHookMsg::BidPlaced {
+        period: period_id,
+        bidder: address.clone(),
+        amount: amount_paid,
+        total: bid.amount,
+    }
+    .into_submsgs(deps.storage)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "bid")
+        .add_attribute("address", address.to_string())
+        .add_attribute("payer", info.sender.to_string())
+        .add_attribute("amount", amount_paid.to_string())
+        .add_attribute("new_amount", new_amount.to_string())
+        .add_submessages(hooks))
+}
+
+/// Verifies that `sender` can add `amount_paid` to the bid of `address`, returning the new bid amount
+/// Attestations are verified separately, as they are only available when executing a bid
+fn validate_bid(
+    deps: Deps,
+    env: &Env,
+    bidding_period: &BiddingPeriod,
+    sender: &Addr,
+    address: &Addr,
+    amount_paid: Uint128,
+    bid: Option<&Bid>,
+) -> Result<Uint128, ContractError> {
+    // Bids cannot be submitted while the contract is paused
+    if is_paused(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    // Verify that the bidding period has started and is not expired
+    if let Some(starts_at) = bidding_period.starts_at {
+        if !starts_at.is_triggered(&env.block) {
+            return Err(ContractError::BiddingPeriodNotStarted { starts_at });
+        }
+    }
+    if bidding_period.expires_at.is_expired(&env.block) {
+        return Err(ContractError::BiddingPeriodExpired {});
+    }
+
+    // Verify that the amount paid is over the minimum bid amount
+    if amount_paid < bidding_period.minimum_bid {
+        return Err(ContractError::BidBelowMinimum {
+            minimum: bidding_period.minimum_bid,
+        });
+    }
+
+    // Verify that neither the sender nor the bidder are denylisted
+    for addr in [sender, address] {
+        if DENYLIST.has(deps.storage, addr) {
+            return Err(ContractError::Denylisted {
+                address: addr.to_string(),
+            });
+        }
+    }
+
+    let new_amount = match bid {
+        Some(bid) => bid.amount + amount_paid,
+        None => amount_paid,
+    };
+
+    // Enforce the bidding period's limits
+    match bid {
+        Some(_) => {
+            if let Some(min_increment) = bidding_period.min_increment {
+                if amount_paid < min_increment {
+                    return Err(ContractError::IncrementTooLow { min: min_increment });
+                }
+            }
+        }
+        None => {
+            if let Some(max_bidders) = bidding_period.max_bidders {
+                let stats = STATS.may_load(deps.storage)?.unwrap_or_default();
+                if stats.bidder_count >= max_bidders {
+                    return Err(ContractError::MaxBiddersReached { max: max_bidders });
+                }
+            }
+        }
+    }
+    validate_holder(deps, bidding_period, address, new_amount)?;
+
+    Ok(new_amount)
+}
+
+/// Verifies that `address` can hold a bid of `new_amount` in the bidding period
+fn validate_holder(
+    deps: Deps,
+    bidding_period: &BiddingPeriod,
+    address: &Addr,
+    new_amount: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(max_bid) = bidding_period.max_bid_per_address {
+        if new_amount > max_bid {
+            return Err(ContractError::MaxBidExceeded { max: max_bid });
+        }
+    }
+
+    // If the bidding period is gated by a cw4 group, only members can hold a bid
+    if let Some(group) = &bidding_period.group {
+        let member: MemberResponse = deps.querier.query_wasm_smart(
+            &group.contract,
+            &Cw4QueryMsg::Member {
+                addr: address.to_string(),
+                at_height: None,
+            },
+        )?;
+        let weight = member.weight.ok_or_else(|| ContractError::NotGroupMember {
+            address: address.to_string(),
+        })?;
+
+        // Verify that the new bid does not exceed the member's cap
+        if let Some(cap_per_weight) = group.cap_per_weight {
+            let cap = cap_per_weight.checked_mul(Uint128::from(weight))?;
+            if new_amount > cap {
+                return Err(ContractError::MemberCapExceeded { cap });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Only JunoBid contracts can be migrated
+    let previous = get_contract_version(deps.storage)?;
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::CannotMigrate {
+            previous_contract: previous.contract,
+        });
+    }
+
+    // Refuse to downgrade the contract
+    let previous_version: Version = previous.version.parse()?;
+    let version: Version = CONTRACT_VERSION.parse()?;
+    if previous_version > version {
+        return Err(ContractError::CannotMigrateVersion {
+            previous_version: previous.version,
+        });
+    }
+
+    // Upgrade the state layout of each previous version in turn
+    if previous_version < Version::new(0, 2, 0) {
+        migrations::v0_1::migrate(deps.storage, &env)?;
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("previous_version", previous.version)
+        .add_attribute("version", CONTRACT_VERSION))
+}
+
+/// Handles the failures of hooks configured to ignore them
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        HOOK_REPLY_ID => match msg.result {
+            SubMsgResult::Err(error) => Ok(Response::new()
+                .add_attribute("method", "hook_failed")
+                .add_attribute("error", error)),
+            // Hooks are only sent with `reply_on_error`
+            SubMsgResult::Ok(_) => Err(ContractError::UnexpectedReply { id: msg.id }),
+        },
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+/// Entry point for chain governance, bypassing the admins
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::ForceCancel {} => cancel_bidding(deps),
+        SudoMsg::UpdateAdmins { admins, threshold } => {
+            let threshold = match threshold {
+                Some(threshold) => threshold,
+                None => ADMINS.load(deps.storage)?.threshold,
+            };
+            update_admins(deps, admins, threshold)
+        }
+        SudoMsg::Pause {} => pause(deps, env),
+        SudoMsg::Unpause {} => unpause(deps),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::BiddingPeriod {} => to_binary(&query_bidding_period(deps)?),
+        QueryMsg::Bids {} => to_binary(&query_bids(deps)?),
+        QueryMsg::Bid { address } => to_binary(&query_bid(deps, address)?),
+        QueryMsg::BidHistory {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&query_bid_history(deps, address, start_after, limit)?),
+        QueryMsg::Schedule {} => to_binary(&query_schedule(deps)?),
+        QueryMsg::PendingSettlement {} => to_binary(&query_pending_settlement(deps)?),
+        QueryMsg::PauseStatus {} => to_binary(&query_pause_status(deps)?),
+        QueryMsg::Stats {} => to_binary(&query_stats(deps)?),
+        QueryMsg::SimulateEndBidding {
+            accepted_bids,
+            withdrawal_address,
+            sender,
+        } => to_binary(&query_simulate_end_bidding(
+            deps,
+            accepted_bids,
+            withdrawal_address,
+            sender,
+        )?),
+        QueryMsg::SimulateBid { address, amount } => {
+            to_binary(&query_simulate_bid(deps, env, address, amount)?)
+        }
+        QueryMsg::Denylist { start_after, limit } => {
+            to_binary(&query_denylist(deps, start_after, limit)?)
+        }
+        QueryMsg::Receipt { period_id, address } => {
+            to_binary(&query_receipt(deps, period_id, address)?)
+        }
+        QueryMsg::Hooks {} => to_binary(&query_hooks(deps)?),
+        QueryMsg::PendingAdminTransfer {} => to_binary(&query_pending_admin_transfer(deps)?),
+        QueryMsg::Roles { address } => to_binary(&query_roles(deps, address)?),
+        QueryMsg::Treasury {} => to_binary(&query_treasury(deps)?),
+        QueryMsg::Proposal { proposal_id } => to_binary(&query_proposal(deps, proposal_id)?),
+        QueryMsg::Proposals {
+            pending,
+            start_after,
+            limit,
+        } => to_binary(&query_proposals(
+            deps,
+            env,
+            pending.unwrap_or(false),
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Operators {
+            owner,
+            include_expired,
+            start_after,
+            limit,
+        } => to_binary(&query_operators(
+            deps,
+            env,
+            owner,
+            include_expired.unwrap_or(false),
+            start_after,
+            limit,
+        )?),
+    }
+}
+
+fn query_bidding_period(deps: Deps) -> StdResult<BiddingPeriodResponse> {
+    let bidding_period = BIDDING_PERIOD.may_load(deps.storage)?;
+    let period_id = match bidding_period {
+        Some(_) => PERIOD_ID.may_load(deps.storage)?,
+        None => None,
+    };
+    Ok(BiddingPeriodResponse {
+        bidding_period,
+        period_id,
+    })
+}
+
+fn query_bids(deps: Deps) -> StdResult<BidsResponse> {
+    let bids = BIDS
+        .keys(deps.storage, None, None, Order::Descending)
+        .map(|res| res.map(|addr| BIDS.load(deps.storage, &addr).unwrap()))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(BidsResponse { bids })
 }
 
 fn query_bid(deps: Deps, address: Addr) -> StdResult<BidResponse> {
     let bid = BIDS.may_load(deps.storage, &address)?;
     Ok(BidResponse { bid })
 }
+
+fn query_schedule(deps: Deps) -> StdResult<ScheduleResponse> {
+    let schedule = SCHEDULE.may_load(deps.storage)?;
+    Ok(ScheduleResponse { schedule })
+}
+
+fn query_pending_settlement(deps: Deps) -> StdResult<PendingSettlementResponse> {
+    let settlement = PENDING_SETTLEMENT.may_load(deps.storage)?;
+    Ok(PendingSettlementResponse { settlement })
+}
+
+fn query_pause_status(deps: Deps) -> StdResult<PauseStatusResponse> {
+    let paused_at = PAUSED_AT.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    Ok(PauseStatusResponse {
+        paused: paused_at.is_some(),
+        paused_at,
+        emergency_withdraw_at: paused_at
+            .map(|paused_at| paused_at.plus_seconds(config.emergency_withdraw_delay)),
+    })
+}
+
+fn query_stats(deps: Deps) -> StdResult<StatsResponse> {
+    let stats = STATS.may_load(deps.storage)?.unwrap_or_default();
+    Ok(StatsResponse { stats })
+}
+
+fn query_simulate_bid(
+    deps: Deps,
+    env: Env,
+    address: Addr,
+    amount: Uint128,
+) -> StdResult<SimulateBidResponse> {
+    let bidding_period = match BIDDING_PERIOD.may_load(deps.storage)? {
+        Some(bidding_period) => bidding_period,
+        None => {
+            return Ok(SimulateBidResponse {
+                rank: 0,
+                accepted: false,
+                top_up_needed: Uint128::zero(),
+                error: Some(ContractError::NoBiddingPeriod {}.to_string()),
+            })
+        }
+    };
+    let bid = BIDS.may_load(deps.storage, &address)?;
+    let error = validate_bid(
+        deps,
+        &env,
+        &bidding_period,
+        &address,
+        &address,
+        amount,
+        bid.as_ref(),
+    )
+    .err()
+    .map(|err| err.to_string());
+    let total = bid.map(|bid| bid.amount).unwrap_or_default() + amount;
+
+    // Other bids, from highest to lowest
+    let other_bids = || {
+        BID_RANKING
+            .keys(deps.storage, None, None, Order::Descending)
+            .filter(|key| !matches!(key, Ok((_, bidder)) if bidder == &address))
+    };
+
+    // Count the other bids ranking higher, ties being ordered by address
+    let mut rank = 1;
+    for key in other_bids() {
+        let (other_amount, bidder) = key?;
+        if (other_amount, &bidder) < (total.u128(), &address) {
+            break;
+        }
+        rank += 1;
+    }
+    let accepted = rank <= bidding_period.accepted_bidders;
+
+    // To be accepted, the bid must outrank the lowest of the `accepted_bidders` highest other bids
+    let mut top_up_needed = Uint128::zero();
+    if !accepted {
+        let cutoff = other_bids()
+            .nth(bidding_period.accepted_bidders as usize - 1)
+            .transpose()?;
+        if let Some((cutoff_amount, bidder)) = cutoff {
+            let needed = if address > bidder {
+                cutoff_amount
+            } else {
+                cutoff_amount + 1
+            };
+            top_up_needed = Uint128::new(needed).saturating_sub(total);
+        }
+    }
+
+    Ok(SimulateBidResponse {
+        rank,
+        accepted,
+        top_up_needed,
+        error,
+    })
+}
+
+fn query_simulate_end_bidding(
+    deps: Deps,
+    accepted_bids: Vec<Addr>,
+    withdrawal_address: Option<Addr>,
+    sender: Option<Addr>,
+) -> StdResult<SimulateEndBiddingResponse> {
+    let outcome = BIDDING_PERIOD
+        .may_load(deps.storage)
+        .map_err(ContractError::from)
+        .and_then(|bidding_period| bidding_period.ok_or(ContractError::NoBiddingPeriod {}))
+        .and_then(|bidding_period| {
+            let settlement = compute_settlement(
+                deps,
+                &bidding_period,
+                &accepted_bids,
+                withdrawal_address.as_ref(),
+            )?;
+            let address = resolve_withdrawal_address(deps, sender.as_ref(), withdrawal_address)?;
+            let split = PROCEEDS_SPLIT.may_load(deps.storage)?.unwrap_or_default();
+            Ok((settlement, address, split, bidding_period.dispute.is_some()))
+        });
+
+    Ok(match outcome {
+        Ok((settlement, address, split, held)) => SimulateEndBiddingResponse {
+            accepted_bids: settlement.accepted,
+            refunds: settlement.refunds,
+            withdrawal_amount: settlement.proceeds,
+            withdrawal_address: Some(address),
+            split,
+            held,
+            error: None,
+        },
+        Err(err) => SimulateEndBiddingResponse {
+            accepted_bids: vec![],
+            refunds: vec![],
+            withdrawal_amount: Uint128::zero(),
+            withdrawal_address: None,
+            split: vec![],
+            held: false,
+            error: Some(err.to_string()),
+        },
+    })
+}
+
+fn query_bid_history(
+    deps: Deps,
+    address: Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<BidHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let contributions = BID_HISTORY
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|(_, contribution)| contribution))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(BidHistoryResponse { contributions })
+}
+
+fn query_denylist(
+    deps: Deps,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<DenylistResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let addresses = DENYLIST
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(DenylistResponse { addresses })
+}
+
+fn query_receipt(deps: Deps, period_id: u64, address: Addr) -> StdResult<ReceiptResponse> {
+    let receipt = RECEIPTS.may_load(deps.storage, (period_id, &address))?;
+    Ok(ReceiptResponse { receipt })
+}
+
+fn query_hooks(deps: Deps) -> StdResult<HooksResponse> {
+    let hooks = HOOKS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(address, config)| HookInfo {
+                address,
+                on_error: config.on_error,
+                gas_limit: config.gas_limit,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(HooksResponse { hooks })
+}
+
+fn query_pending_admin_transfer(deps: Deps) -> StdResult<PendingAdminTransferResponse> {
+    let transfer = PENDING_ADMIN_TRANSFER.may_load(deps.storage)?;
+    Ok(PendingAdminTransferResponse { transfer })
+}
+
+fn query_roles(deps: Deps, address: Addr) -> StdResult<RolesResponse> {
+    let roles = if can_execute(deps, address.as_ref())? {
+        vec![Role::Owner, Role::Operator, Role::Settler, Role::Treasurer]
+    } else {
+        ROLES.may_load(deps.storage, &address)?.unwrap_or_default()
+    };
+    Ok(RolesResponse { roles })
+}
+
+fn query_treasury(deps: Deps) -> StdResult<TreasuryResponse> {
+    let address = TREASURY.may_load(deps.storage)?;
+    let split = PROCEEDS_SPLIT.may_load(deps.storage)?.unwrap_or_default();
+    Ok(TreasuryResponse { address, split })
+}
+
+fn query_proposal(deps: Deps, proposal_id: u64) -> StdResult<ProposalResponse> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    Ok(ProposalResponse { proposal })
+}
+
+fn query_proposals(
+    deps: Deps,
+    env: Env,
+    pending: bool,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ProposalsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let proposals = PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| item.map(|(_, proposal)| proposal))
+        .filter(|item| match item {
+            Ok(proposal) if pending => {
+                !proposal.executed && !proposal.expires.is_expired(&env.block)
+            }
+            _ => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(ProposalsResponse { proposals })
+}
+
+fn query_operators(
+    deps: Deps,
+    env: Env,
+    owner: Addr,
+    include_expired: bool,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<OperatorsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+    let operators = OPERATORS
+        .prefix(&owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| {
+            include_expired
+                || item
+                    .as_ref()
+                    .map_or(true, |(_, expires)| !expires.is_expired(&env.block))
+        })
+        .take(limit)
+        .map(|item| {
+            let (operator, expires) = item?;
+            Ok(OperatorInfo { operator, expires })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(OperatorsResponse { operators })
+}
@@ -1,5 +1,5 @@
-use cosmwasm_std::StdError;
-use cw_utils::PaymentError;
+use cosmwasm_std::{OverflowError, StdError, Timestamp, Uint128};
+use cw_utils::{PaymentError, Scheduled};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,10 +8,18 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    /// Wrapper for `OverflowError`
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
     /// Wrapper for `PaymentError`
     #[error("{0}")]
     Payment(#[from] PaymentError),
 
+    /// Wrapper for semver parsing errors
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
     /// Resource not found
     #[error("NotFound")]
     NotFound {},
@@ -28,7 +36,262 @@ pub enum ContractError {
     #[error("Bidding Period Expired")]
     BiddingPeriodExpired {},
 
-    /// Custom error with value
-    #[error("Custom Error val: {val:?}")]
-    CustomErrorParam { val: String },
+    /// The address has been denylisted
+    #[error("Denylisted: {address}")]
+    Denylisted { address: String },
+
+    /// The contract is paused
+    #[error("Paused")]
+    Paused {},
+
+    /// The contract is not paused
+    #[error("Not Paused")]
+    NotPaused {},
+
+    /// Emergency withdrawals are not available yet
+    #[error("Emergency Withdraw Unavailable until {available_at}")]
+    EmergencyWithdrawUnavailable { available_at: Timestamp },
+
+    /// A schedule must have a non-zero interval and count
+    #[error("Invalid Schedule")]
+    InvalidSchedule {},
+
+    /// There is no schedule, or all of its bidding periods have started
+    #[error("No Scheduled Bidding Period")]
+    NoSchedule {},
+
+    /// There is no current bidding period
+    #[error("No Bidding Period")]
+    NoBiddingPeriod {},
+
+    /// The proceeds of the previous bidding period have not been released or vetoed yet
+    #[error("Settlement Pending")]
+    SettlementPending {},
+
+    /// There is no settlement awaiting release
+    #[error("No Pending Settlement")]
+    NoPendingSettlement {},
+
+    /// The proceeds cannot be released until the dispute window closes
+    #[error("Dispute Window Open until {releasable_at}")]
+    DisputeWindowOpen { releasable_at: Timestamp },
+
+    /// The result can no longer be vetoed
+    #[error("Dispute Window Closed")]
+    DisputeWindowClosed {},
+
+    /// The bidding period does not allow bids to be transferred
+    #[error("Bid Transfers Disabled")]
+    BidTransfersDisabled {},
+
+    /// A bidder cannot approve themselves as an operator
+    #[error("Cannot Approve Self")]
+    CannotApproveSelf {},
+
+    /// The address is not an operator of the sender
+    #[error("Operator Not Approved: {operator}")]
+    OperatorNotApproved { operator: String },
+
+    /// A bid cannot be transferred to its own bidder
+    #[error("Cannot Transfer To Self")]
+    CannotTransferToSelf {},
+
+    /// The bidder must acknowledge the bidding period's terms of sale
+    #[error("Terms Not Accepted: {terms_hash}")]
+    TermsNotAccepted { terms_hash: String },
+
+    /// The bid would exceed the maximum bid per address
+    #[error("Max Bid Exceeded: {max}")]
+    MaxBidExceeded { max: Uint128 },
+
+    /// The bidding period has reached its maximum number of bidders
+    #[error("Max Bidders Reached: {max}")]
+    MaxBiddersReached { max: u64 },
+
+    /// The top-up is below the minimum increment
+    #[error("Increment Too Low: {min}")]
+    IncrementTooLow { min: Uint128 },
+
+    /// The bidder is not a member of the cw4 group gating the bidding period
+    #[error("Not Group Member: {address}")]
+    NotGroupMember { address: String },
+
+    /// The bid would exceed the member's weight-scaled cap
+    #[error("Member Cap Exceeded: {cap}")]
+    MemberCapExceeded { cap: Uint128 },
+
+    /// The bidding period requires an attestation to bid
+    #[error("Attestation Required")]
+    AttestationRequired {},
+
+    /// The attestation signature does not match the bidding period's attestor
+    #[error("Invalid Attestation")]
+    InvalidAttestation {},
+
+    /// The attestation can no longer be used
+    #[error("Attestation Expired")]
+    AttestationExpired {},
+
+    /// The bid would exceed the amount authorized by the attestation
+    #[error("Attestation Limit Exceeded: {max_amount}")]
+    AttestationLimitExceeded { max_amount: Uint128 },
+
+    /// The attestation nonce has already been used by this bidder
+    #[error("Nonce Already Used: {nonce}")]
+    NonceAlreadyUsed { nonce: u64 },
+
+    /// The contract being migrated is not a JunoBid contract
+    #[error("Cannot migrate from a different contract: {previous_contract}")]
+    CannotMigrate { previous_contract: String },
+
+    /// The contract being migrated is newer than this version
+    #[error("Cannot migrate from a newer version: {previous_version}")]
+    CannotMigrateVersion { previous_version: String },
+
+    /// The action must be proposed and approved by `threshold` admins
+    #[error("Proposal Required")]
+    ProposalRequired {},
+
+    /// The address is already an admin
+    #[error("Already Admin: {address}")]
+    AlreadyAdmin { address: String },
+
+    /// Another admin's transfer is pending
+    #[error("Admin Transfer Pending")]
+    AdminTransferPending {},
+
+    /// There is no admin transfer to accept or cancel
+    #[error("No Pending Admin Transfer")]
+    NoPendingAdminTransfer {},
+
+    /// The admin transfer can no longer be accepted
+    #[error("Admin Transfer Expired")]
+    AdminTransferExpired {},
+
+    /// The admin who proposed the transfer no longer holds a seat to hand over
+    #[error("Admin Seat Vacated: {address}")]
+    AdminSeatVacated { address: String },
+
+    /// The sender did not win the bidding period, or it had no receipt collection
+    #[error("No Receipt for period {period_id}")]
+    NoReceipt { period_id: u64 },
+
+    /// The receipt has already been minted
+    #[error("Receipt Already Claimed for period {period_id}")]
+    ReceiptAlreadyClaimed { period_id: u64 },
+
+    /// The hook contract is already registered
+    #[error("Hook Already Registered: {address}")]
+    HookAlreadyRegistered { address: String },
+
+    /// The hook contract is not registered
+    #[error("Hook Not Registered: {address}")]
+    HookNotRegistered { address: String },
+
+    /// The contract received a reply to a submessage it did not send
+    #[error("Unknown Reply Id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    /// The contract received a reply to a submessage that only replies on error
+    #[error("Unexpected Successful Reply: {id}")]
+    UnexpectedReply { id: u64 },
+
+    /// The owner role is held by the admins, and cannot be granted
+    #[error("Owner Role Not Grantable")]
+    OwnerNotGrantable {},
+
+    /// The threshold must be between 1 and the number of admins
+    #[error("Invalid Threshold")]
+    InvalidThreshold {},
+
+    /// The proposal can no longer be approved
+    #[error("Proposal Expired")]
+    ProposalExpired {},
+
+    /// The proposal has already been executed
+    #[error("Proposal Already Executed")]
+    ProposalExecuted {},
+
+    /// The admin has already approved the proposal
+    #[error("Already Approved")]
+    AlreadyApproved {},
+
+    /// There is no proposal with this id
+    #[error("Proposal Not Found: {proposal_id}")]
+    ProposalNotFound { proposal_id: u64 },
+
+    /// The bidding period does not accept bids yet
+    #[error("Bidding Period Not Started until {starts_at}")]
+    BiddingPeriodNotStarted { starts_at: Scheduled },
+
+    /// The bidding period's expiry is not in the future, never comes, or precedes its start
+    #[error("Invalid Expiry")]
+    InvalidExpiry {},
+
+    /// A metadata field exceeds its length limit
+    #[error("Metadata Too Long: {field} exceeds {max}")]
+    MetadataTooLong { field: String, max: usize },
+
+    /// The terms hash must be a hex-encoded SHA-256 hash
+    #[error("Invalid Terms Hash")]
+    InvalidTermsHash {},
+
+    /// The field of the active bidding period cannot be changed
+    #[error("Immutable Field: {field}")]
+    ImmutableField { field: String },
+
+    /// The active bidding period's expiry can only be extended
+    #[error("Expiry Shortened")]
+    ExpiryShortened {},
+
+    /// The active bidding period's number of accepted bids can only be raised
+    #[error("Accepted Bidders Lowered")]
+    AcceptedBiddersLowered {},
+
+    /// Existing bids would fall below the new minimum bid
+    #[error("Minimum Above Existing Bids: {minimum}")]
+    MinimumAboveBids { minimum: Uint128 },
+
+    /// The bidding period must be able to accept at least 1 bid
+    #[error("Invalid Accepted Bidders")]
+    InvalidAcceptedBidders {},
+
+    /// The bid is below the bidding period's minimum bid
+    #[error("Bid Below Minimum: {minimum}")]
+    BidBelowMinimum { minimum: Uint128 },
+
+    /// More bids are accepted than the bidding period allows
+    #[error("Too Many Accepted Bids: {max}")]
+    TooManyAcceptedBids { max: u64 },
+
+    /// The address has no bid in the current bidding period
+    #[error("Unknown Bidder: {address}")]
+    UnknownBidder { address: String },
+
+    /// The same address is listed more than once as an admin
+    #[error("Duplicate Admin: {address}")]
+    DuplicateAdmin { address: String },
+
+    /// The same bid is accepted more than once
+    #[error("Duplicate Accepted Bid: {address}")]
+    DuplicateAcceptedBid { address: String },
+
+    /// The withdrawal address is not a valid address
+    #[error("Invalid Withdrawal Address: {address}")]
+    InvalidWithdrawalAddress { address: String },
+
+    /// Without a treasury, only a treasurer can end the bidding period without a withdrawal address
+    #[error("Withdrawal Address Required")]
+    WithdrawalAddressRequired {},
+
+    /// The proceeds split has more than 10 shares, a share of zero or a duplicate address,
+    /// or its shares add up to more than 10,000 basis points
+    #[error("Invalid Proceeds Split")]
+    InvalidProceedsSplit {},
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
 }
@@ -0,0 +1,97 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Addr, Order, StdResult, Storage, SubMsg, Uint128, WasmMsg};
+use cw_storage_plus::Map;
+
+use crate::state::Bid;
+
+/// Reply id of the hook submessages whose failures are ignored
+pub const HOOK_REPLY_ID: u64 = 1;
+/// Gas available to a hook whose failures are ignored, unless set when registering it
+pub const DEFAULT_HOOK_GAS_LIMIT: u64 = 300_000;
+
+/// How a failing hook is handled
+#[cw_serde]
+pub enum HookFailure {
+    /// The failure is logged, and the message that triggered the hook goes through
+    Ignore,
+    /// The failure reverts the message that triggered the hook
+    Revert,
+}
+
+#[cw_serde]
+pub struct HookConfig {
+    pub on_error: HookFailure,
+    /// Gas available to the hook whenever its failures are ignored, so that running out
+    /// of gas cannot revert the notifying message either
+    pub gas_limit: u64,
+}
+
+/// Notification sent to the registered hooks
+#[cw_serde]
+pub enum BidHookMsg {
+    /// `amount` was added to `bidder`'s bid, for a `total` of the bid
+    BidPlaced {
+        period: u64,
+        bidder: Addr,
+        amount: Uint128,
+        total: Uint128,
+    },
+    /// `bidder`'s bid of `amount` was refunded, as it was withdrawn or disqualified,
+    /// its bidding period was cancelled, or its settlement was vetoed
+    BidWithdrawn {
+        period: u64,
+        bidder: Addr,
+        amount: Uint128,
+    },
+    /// `from`'s bid of `amount` was transferred to `to`, for a `total` of `to`'s bid
+    BidTransferred {
+        period: u64,
+        from: Addr,
+        to: Addr,
+        amount: Uint128,
+        total: Uint128,
+    },
+    /// The proceeds of the bidding period's accepted bids were released
+    PeriodSettled { period: u64, winners: Vec<Bid> },
+}
+
+/// Message received by the hook contracts, wrapping the notification
+#[cw_serde]
+pub enum BidHookExecuteMsg {
+    BidHook(BidHookMsg),
+}
+
+impl BidHookMsg {
+    /// Submessages notifying every registered hook, handling its failures as configured
+    pub fn into_submsgs(self, storage: &dyn Storage) -> StdResult<Vec<SubMsg>> {
+        self.submsgs(storage, false)
+    }
+
+    /// Submessages notifying every registered hook, ignoring the failures of all hooks
+    /// so that none of them can block the refund of bids
+    pub fn into_ignored_submsgs(self, storage: &dyn Storage) -> StdResult<Vec<SubMsg>> {
+        self.submsgs(storage, true)
+    }
+
+    fn submsgs(self, storage: &dyn Storage, ignore_failures: bool) -> StdResult<Vec<SubMsg>> {
+        let msg = to_binary(&BidHookExecuteMsg::BidHook(self))?;
+        HOOKS
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (contract_addr, config) = item?;
+                let execute = WasmMsg::Execute {
+                    contract_addr: contract_addr.into(),
+                    msg: msg.clone(),
+                    funds: vec![],
+                };
+                Ok(match config.on_error {
+                    HookFailure::Revert if !ignore_failures => SubMsg::new(execute),
+                    _ => SubMsg::reply_on_error(execute, HOOK_REPLY_ID)
+                        .with_gas_limit(config.gas_limit),
+                })
+            })
+            .collect()
+    }
+}
+
+pub const HOOKS: Map<&Addr, HookConfig> = Map::new("hooks");
@@ -1,8 +1,13 @@
 pub mod admins;
+pub mod attestation;
 pub mod contract;
 mod error;
 pub mod helpers;
+pub mod hooks;
+pub mod migrations;
 pub mod msg;
+pub mod proposals;
+pub mod receipts;
 pub mod state;
 pub mod tests;
 
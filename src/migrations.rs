@@ -0,0 +1,107 @@
+/// State layout of v0.1.x, where bids did not record their payers
+pub mod v0_1 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Addr, Env, Order, StdResult, Storage, Timestamp, Uint128};
+    use cw_storage_plus::{Item, Map};
+    use cw_utils::Expiration;
+
+    use crate::state::{self, Contribution, PayerShare, RefundTarget};
+
+    #[cw_serde]
+    pub struct Bid {
+        pub bidder: Addr,
+        pub amount: Uint128,
+    }
+
+    #[cw_serde]
+    pub struct BiddingPeriod {
+        pub name: String,
+        pub description: Option<String>,
+        pub expires_at: Timestamp,
+        pub minimum_bid: Uint128,
+        pub accepted_bidders: u64,
+        pub denom: String,
+    }
+
+    pub const BIDS: Map<&Addr, Bid> = Map::new("bids");
+    pub const BIDDING_PERIOD: Item<BiddingPeriod> = Item::new("bidding_period");
+
+    /// Converts the v0.1 bidding period and bids to the current layout
+    /// v0.1 did not record when bids were made, so each bid is recorded as a
+    /// single contribution made at the time of the migration
+    pub fn migrate(storage: &mut dyn Storage, env: &Env) -> StdResult<()> {
+        state::CONFIG.save(
+            storage,
+            &state::Config {
+                emergency_withdraw_delay: state::DEFAULT_EMERGENCY_WITHDRAW_DELAY,
+            },
+        )?;
+
+        let mut accepted_bidders = 0;
+        if let Some(period) = BIDDING_PERIOD.may_load(storage)? {
+            accepted_bidders = period.accepted_bidders;
+            state::BIDDING_PERIOD.save(
+                storage,
+                &state::BiddingPeriod {
+                    name: period.name,
+                    description: period.description,
+                    starts_at: None,
+                    expires_at: Expiration::AtTime(period.expires_at),
+                    minimum_bid: period.minimum_bid,
+                    accepted_bidders: period.accepted_bidders,
+                    denom: period.denom,
+                    max_bid_per_address: None,
+                    max_bidders: None,
+                    min_increment: None,
+                    refund_to: RefundTarget::Beneficiary,
+                    group: None,
+                    attestor: None,
+                    dispute: None,
+                    receipt_collection: None,
+                    metadata: None,
+                    transferable_bids: false,
+                },
+            )?;
+            // v0.1 did not number its bidding periods
+            state::PERIOD_ID.save(storage, &1)?;
+        }
+        let period_id = state::PERIOD_ID.may_load(storage)?.unwrap_or_default();
+
+        // Each v0.1 bid is attributed to its bidder, and ranked
+        let bids = BIDS
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        BIDS.clear(storage);
+        for (address, bid) in bids {
+            state::save_bid(
+                storage,
+                &state::Bid {
+                    bidder: bid.bidder.clone(),
+                    amount: bid.amount,
+                    payers: vec![PayerShare {
+                        payer: bid.bidder.clone(),
+                        amount: bid.amount,
+                    }],
+                    first_bid_at: env.block.time,
+                    last_bid_at: env.block.time,
+                    terms_hash: None,
+                },
+                accepted_bidders,
+            )?;
+            state::append_contribution(
+                storage,
+                &address,
+                Contribution {
+                    index: 0,
+                    period_id,
+                    payer: bid.bidder,
+                    amount: bid.amount,
+                    time: env.block.time,
+                    height: env.block.height,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
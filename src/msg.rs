@@ -1,30 +1,176 @@
-use crate::state::{Bid, BiddingPeriod};
+use crate::admins::{AdminTransfer, Role};
+use crate::attestation::Attestation;
+use crate::hooks::HookFailure;
+use crate::proposals::{Proposal, ProposalAction};
+use crate::receipts::Receipt;
+use crate::state::{
+    Bid, BiddingPeriod, Contribution, Payout, PendingSettlement, PeriodStats, ProceedsShare,
+    Schedule,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_utils::{Duration, Expiration};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admins: Vec<Addr>,
+    /// Number of approvals needed for the actions that can be proposed. Defaults to 1
+    pub threshold: Option<u64>,
+    /// Seconds after which bidders can withdraw their bids from a paused contract
+    /// Defaults to 7 days
+    pub emergency_withdraw_delay: Option<u64>,
 }
 
 #[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     /// - ADMIN FACING -
-    /// Start a new bidding period
+    /// Privileged messages are restricted to the holders of a role. Admins hold every role
+    /// Start a new bidding period. Requires the `operator` role
+    /// Only available when the threshold is 1, use a proposal otherwise
     StartBidding { config: BiddingPeriod },
     /// End the current bidding period
     /// This will return all the bids to the bidders, except the accepted bids
     /// All remaining balance will be withdrawn to the address that executed the msg,
     /// unless `withdrawal_address` is specified, in which case the funds will be
-    /// transferred to that address
+    /// transferred to that address, which requires the `treasurer` role
+    /// Without a `withdrawal_address`, the funds are withdrawn to the treasury if one is set
+    /// Requires the `settler` role
+    /// Only available when the threshold is 1, use a proposal otherwise
     EndBidding {
         accepted_bids: Vec<Addr>,
         withdrawal_address: Option<Addr>,
     },
+    /// Update the current bidding period. Only the name and description can be edited,
+    /// the expiry extended, the number of accepted bids raised, and the minimum bid
+    /// changed as long as existing bids do not fall below it. Requires the `operator` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    UpdateBiddingPeriod { config: BiddingPeriod },
+    /// Schedule `count` bidding periods derived from `template`, each expiring `interval`
+    /// after it starts, and named after the template with a sequence suffix
+    /// Replaces the current schedule. Requires the `operator` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    CreateSchedule {
+        template: BiddingPeriod,
+        interval: Duration,
+        count: u64,
+    },
+    /// Cancel the remaining bidding periods of the schedule. Requires the `operator` role
+    CancelSchedule {},
+    /// Cancel the current bidding period, refunding all bids. Requires the `operator` role
+    CancelBidding {},
+    /// Grant a role to an address. Requires the `owner` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    GrantRole { address: Addr, role: Role },
+    /// Revoke a role from an address. Requires the `owner` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    RevokeRole { address: Addr, role: Role },
+    /// Set or unset the default withdrawal address. Requires the `treasurer` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    SetTreasury { address: Option<Addr> },
+    /// Set the shares of the proceeds paid to other addresses than the withdrawal address,
+    /// in basis points, the withdrawal address receiving the rest. Requires the `treasurer` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    SetProceedsSplit { split: Vec<ProceedsShare> },
+    /// Nominate an address to take over the sender's admin seat
+    /// The transfer takes effect once the nominee accepts it, before `expiry`
+    ProposeAdminTransfer { new_admin: Addr, expiry: Expiration },
+    /// Accept the pending admin transfer. Can only be called by the nominee
+    AcceptAdminTransfer {},
+    /// Cancel the pending admin transfer
+    CancelAdminTransfer {},
+    /// Propose a privileged action, approved by the proposer, who must hold the
+    /// roles the action requires
+    /// The action is executed as soon as `threshold` holders of these roles have approved it
+    Propose {
+        action: ProposalAction,
+        expires: Expiration,
+    },
+    /// Approve a pending proposal, executing it if the threshold is reached
+    Approve { proposal_id: u64 },
+    /// Disqualify a bid, refunding it immediately. Requires the `operator` role
+    /// If `denylist` is set, the address is also prevented from bidding again
+    DisqualifyBid {
+        address: Addr,
+        reason: String,
+        denylist: bool,
+    },
+    /// Add addresses to the denylist, whether or not they hold a bid, and remove others from it.
+    /// Requires the `owner` role
+    /// Only available when the threshold is 1, use a proposal otherwise
+    UpdateDenylist { add: Vec<Addr>, remove: Vec<Addr> },
+    /// Register a contract notified of bids and settlements
+    /// `on_error` sets whether the contract's failures are ignored or revert the notifying message
+    /// `gas_limit` caps the gas of the contract whenever its failures are ignored,
+    /// and defaults to 300,000
+    AddHook {
+        address: Addr,
+        on_error: HookFailure,
+        gas_limit: Option<u64>,
+    },
+    /// Unregister a hook contract
+    RemoveHook { address: Addr },
+    /// Veto the result of the ended bidding period during its dispute window,
+    /// refunding the accepted bids. Can only be called by the guardian
+    VetoSettlement {},
+    /// Pause the contract, preventing new bids and the end of the bidding period
+    Pause {},
+    /// Unpause the contract
+    Unpause {},
 
     /// - BIDDER FACING -
     /// Create a new bid, optionally on behalf of another address
-    Bid { address: Option<Addr> },
+    /// If the bidding period has an attestor, a signed `attestation` for the
+    /// bidder is required
+    /// If the bidding period has terms of sale, `terms_hash` must match their hash
+    Bid {
+        address: Option<Addr>,
+        attestation: Option<Attestation>,
+        terms_hash: Option<String>,
+    },
+    /// Withdraw the sender's bid once the contract has been paused for longer
+    /// than the emergency withdrawal delay
+    /// An approved operator can withdraw the bid of `address`
+    EmergencyWithdraw { address: Option<Addr> },
+    /// Transfer the sender's bid to another address, merging it into the
+    /// recipient's bid if they have one
+    /// Only allowed if the bidding period has `transferable_bids` set
+    /// An approved operator can transfer the bid of `from`
+    TransferBid { from: Option<Addr>, to: Addr },
+    /// Allow `operator` to withdraw or transfer the sender's bid, until `expires`
+    ApproveOperator {
+        operator: Addr,
+        expires: Option<Expiration>,
+    },
+    /// Revoke the approval of `operator`
+    RevokeOperator { operator: Addr },
+    /// Mint the sender's receipt for a bidding period they won
+    ClaimReceipt { period_id: u64 },
+
+    /// - PERMISSIONLESS -
+    /// Start the next bidding period of the schedule, once the previous one has ended
+    StartScheduledBidding {},
+    /// Release the proceeds of the ended bidding period once its dispute window has closed
+    ReleaseProceeds {},
+}
+
+/// Messages that can only be sent by chain governance
+#[cw_serde]
+pub enum SudoMsg {
+    /// Cancel the current bidding period, refunding all bids
+    ForceCancel {},
+    /// Replace the admins, keeping the current threshold unless `threshold` is specified
+    UpdateAdmins {
+        admins: Vec<Addr>,
+        threshold: Option<u64>,
+    },
+    /// Pause the contract
+    Pause {},
+    /// Unpause the contract
+    Unpause {},
 }
 
 #[cw_serde]
@@ -36,6 +182,63 @@ pub enum QueryMsg {
     Bids {},
     #[returns(BidResponse)]
     Bid { address: Addr },
+    #[returns(ScheduleResponse)]
+    Schedule {},
+    #[returns(PendingSettlementResponse)]
+    PendingSettlement {},
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    #[returns(StatsResponse)]
+    Stats {},
+    #[returns(SimulateBidResponse)]
+    SimulateBid { address: Addr, amount: Uint128 },
+    /// `sender` is the address that would end the bidding period, which receives
+    /// the proceeds if neither a withdrawal address nor a treasury is set
+    #[returns(SimulateEndBiddingResponse)]
+    SimulateEndBidding {
+        accepted_bids: Vec<Addr>,
+        withdrawal_address: Option<Addr>,
+        sender: Option<Addr>,
+    },
+    #[returns(BidHistoryResponse)]
+    BidHistory {
+        address: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(DenylistResponse)]
+    Denylist {
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
+    #[returns(ReceiptResponse)]
+    Receipt { period_id: u64, address: Addr },
+    #[returns(HooksResponse)]
+    Hooks {},
+    #[returns(PendingAdminTransferResponse)]
+    PendingAdminTransfer {},
+    #[returns(RolesResponse)]
+    Roles { address: Addr },
+    #[returns(TreasuryResponse)]
+    Treasury {},
+    #[returns(ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Proposals in the order they were submitted, only those that can still be approved
+    /// if `pending` is set
+    #[returns(ProposalsResponse)]
+    Proposals {
+        pending: Option<bool>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Operators approved by `owner`, excluding expired approvals unless `include_expired` is set
+    #[returns(OperatorsResponse)]
+    Operators {
+        owner: Addr,
+        include_expired: Option<bool>,
+        start_after: Option<Addr>,
+        limit: Option<u32>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -43,6 +246,8 @@ pub enum QueryMsg {
 #[cw_serde]
 pub struct BiddingPeriodResponse {
     pub bidding_period: Option<BiddingPeriod>,
+    /// Sequence number of the current bidding period
+    pub period_id: Option<u64>,
 }
 
 #[cw_serde]
@@ -54,3 +259,124 @@ pub struct BidsResponse {
 pub struct BidResponse {
     pub bid: Option<Bid>,
 }
+
+#[cw_serde]
+pub struct ScheduleResponse {
+    pub schedule: Option<Schedule>,
+}
+
+#[cw_serde]
+pub struct PendingSettlementResponse {
+    pub settlement: Option<PendingSettlement>,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub paused: bool,
+    /// Time at which the contract was paused
+    pub paused_at: Option<Timestamp>,
+    /// Time from which bidders can withdraw their bids, if the contract is paused
+    pub emergency_withdraw_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct StatsResponse {
+    pub stats: PeriodStats,
+}
+
+#[cw_serde]
+pub struct SimulateBidResponse {
+    /// Projected rank of the bid, 1 being the highest bid, or 0 if there is no bidding period
+    pub rank: u64,
+    /// Whether the bid would be among the `accepted_bidders` highest bids
+    pub accepted: bool,
+    /// Minimum amount to add on top of the simulated amount for the bid to be accepted
+    pub top_up_needed: Uint128,
+    /// Error that submitting the bid would raise, if any
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct SimulateEndBiddingResponse {
+    /// Bids that would be accepted
+    pub accepted_bids: Vec<Bid>,
+    /// Refunds that would be sent for the rejected bids
+    pub refunds: Vec<Payout>,
+    /// Amount that would be withdrawn
+    pub withdrawal_amount: Uint128,
+    /// Address the funds would be withdrawn to: the withdrawal address, else the treasury,
+    /// else the sender. `None` if ending the bidding period would fail
+    pub withdrawal_address: Option<Addr>,
+    /// Shares of the withdrawal amount that would be paid to other addresses than the
+    /// withdrawal address
+    pub split: Vec<ProceedsShare>,
+    /// Whether the withdrawal amount would be held until the dispute window closes,
+    /// rather than withdrawn
+    pub held: bool,
+    /// Error that ending the bidding period would raise, if any
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct BidHistoryResponse {
+    pub contributions: Vec<Contribution>,
+}
+
+#[cw_serde]
+pub struct DenylistResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct ReceiptResponse {
+    pub receipt: Option<Receipt>,
+}
+
+#[cw_serde]
+pub struct HookInfo {
+    pub address: Addr,
+    pub on_error: HookFailure,
+    pub gas_limit: u64,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<HookInfo>,
+}
+
+#[cw_serde]
+pub struct PendingAdminTransferResponse {
+    pub transfer: Option<AdminTransfer>,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub address: Option<Addr>,
+    pub split: Vec<ProceedsShare>,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub proposal: Proposal,
+}
+
+#[cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<Proposal>,
+}
+
+#[cw_serde]
+pub struct OperatorInfo {
+    pub operator: Addr,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct OperatorsResponse {
+    pub operators: Vec<OperatorInfo>,
+}
@@ -0,0 +1,97 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+
+use crate::admins::Role;
+use crate::state::{BiddingPeriod, ProceedsShare};
+
+/// Privileged action that needs the approval of `threshold` admins, proposed and approved
+/// by holders of its required roles
+#[cw_serde]
+#[allow(clippy::large_enum_variant)]
+pub enum ProposalAction {
+    StartBidding {
+        config: BiddingPeriod,
+    },
+    EndBidding {
+        accepted_bids: Vec<Addr>,
+        withdrawal_address: Option<Addr>,
+    },
+    UpdateAdmins {
+        admins: Vec<Addr>,
+        threshold: u64,
+    },
+    GrantRole {
+        address: Addr,
+        role: Role,
+    },
+    RevokeRole {
+        address: Addr,
+        role: Role,
+    },
+    UpdateDenylist {
+        add: Vec<Addr>,
+        remove: Vec<Addr>,
+    },
+    SetTreasury {
+        address: Option<Addr>,
+    },
+    SetProceedsSplit {
+        split: Vec<ProceedsShare>,
+    },
+    UpdateBiddingPeriod {
+        config: BiddingPeriod,
+    },
+    CreateSchedule {
+        template: BiddingPeriod,
+        interval: Duration,
+        count: u64,
+    },
+}
+
+impl ProposalAction {
+    /// Roles an address must hold to propose or approve the action
+    pub fn required_roles(&self) -> Vec<Role> {
+        match self {
+            ProposalAction::StartBidding { .. }
+            | ProposalAction::UpdateBiddingPeriod { .. }
+            | ProposalAction::CreateSchedule { .. } => vec![Role::Operator],
+            ProposalAction::EndBidding {
+                withdrawal_address: None,
+                ..
+            } => vec![Role::Settler],
+            ProposalAction::EndBidding {
+                withdrawal_address: Some(_),
+                ..
+            } => vec![Role::Settler, Role::Treasurer],
+            ProposalAction::SetTreasury { .. } | ProposalAction::SetProceedsSplit { .. } => {
+                vec![Role::Treasurer]
+            }
+            ProposalAction::UpdateAdmins { .. }
+            | ProposalAction::GrantRole { .. }
+            | ProposalAction::RevokeRole { .. }
+            | ProposalAction::UpdateDenylist { .. } => vec![Role::Owner],
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    /// Address that submitted the proposal, and receives the proceeds of an
+    /// `end_bidding` action that does not set a withdrawal address when there is
+    /// no treasury, if it is a treasurer
+    pub proposer: Addr,
+    pub action: ProposalAction,
+    /// Addresses that approved the proposal, including the proposer
+    pub approvals: Vec<Addr>,
+    /// Time or height after which the proposal can no longer be approved
+    pub expires: Expiration,
+    /// Whether the action has been executed
+    pub executed: bool,
+}
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+/// Number of proposals submitted so far, used to assign proposal ids
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
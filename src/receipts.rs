@@ -0,0 +1,102 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Addr, StdResult, Storage, Uint128, WasmMsg};
+use cw_storage_plus::Map;
+
+use crate::state::Bid;
+
+/// Proof that a bidder won a bidding period, claimable as an NFT of the period's
+/// receipt collection
+#[cw_serde]
+pub struct Receipt {
+    pub period_id: u64,
+    pub period_name: String,
+    /// Rank of the bid among the accepted bids, starting at 1 for the highest bid
+    pub rank: u64,
+    /// Amount paid for the bid
+    pub amount: Uint128,
+    pub denom: String,
+    /// cw721 collection the receipt is minted in
+    pub collection: Addr,
+    /// Whether the receipt has been minted
+    pub claimed: bool,
+}
+
+/// Metadata of receipt NFTs
+#[cw_serde]
+pub struct ReceiptMetadata {
+    pub period_id: u64,
+    pub period_name: String,
+    pub rank: u64,
+    pub amount: Uint128,
+    pub denom: String,
+}
+
+/// Mint message of cw721 collections, for which this contract must be the minter
+#[cw_serde]
+pub enum Cw721ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: ReceiptMetadata,
+    },
+}
+
+impl Receipt {
+    /// Message minting the receipt to `owner`
+    pub fn mint_msg(&self, owner: &Addr) -> StdResult<WasmMsg> {
+        let msg = Cw721ExecuteMsg::Mint {
+            token_id: format!("{}-{}", self.period_id, self.rank),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: ReceiptMetadata {
+                period_id: self.period_id,
+                period_name: self.period_name.clone(),
+                rank: self.rank,
+                amount: self.amount,
+                denom: self.denom.clone(),
+            },
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.collection.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+}
+
+/// Records a claimable receipt for each winner, ranked by amount
+/// Minting is left to the winners, so that the cost of settlement does not
+/// depend on the cost of minting
+pub fn record_receipts(
+    storage: &mut dyn Storage,
+    period_id: u64,
+    period_name: &str,
+    denom: &str,
+    collection: &Addr,
+    winners: &[Bid],
+) -> StdResult<()> {
+    // Ties are ranked in the same order as the bid ranking
+    let mut winners: Vec<&Bid> = winners.iter().collect();
+    winners.sort_by(|a, b| (b.amount, &b.bidder).cmp(&(a.amount, &a.bidder)));
+
+    for (index, bid) in winners.into_iter().enumerate() {
+        RECEIPTS.save(
+            storage,
+            (period_id, &bid.bidder),
+            &Receipt {
+                period_id,
+                period_name: period_name.to_string(),
+                rank: index as u64 + 1,
+                amount: bid.amount,
+                denom: denom.to_string(),
+                collection: collection.clone(),
+                claimed: false,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Receipts of the winners of each bidding period
+pub const RECEIPTS: Map<(u64, &Addr), Receipt> = Map::new("receipts");
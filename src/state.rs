@@ -1,6 +1,26 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Timestamp, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, BlockInfo, Empty, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::{Duration, Expiration, Scheduled};
+
+use crate::attestation::Attestor;
+
+/// Default delay after which bidders can withdraw their bids from a paused contract
+pub const DEFAULT_EMERGENCY_WITHDRAW_DELAY: u64 = 7 * 24 * 60 * 60;
+
+#[cw_serde]
+pub struct Config {
+    /// Seconds after which bidders can withdraw their bids from a paused contract
+    pub emergency_withdraw_delay: u64,
+}
+
+#[cw_serde]
+pub struct PayerShare {
+    /// Address that paid into the bid
+    pub payer: Addr,
+    /// Amount of tokens paid by this address
+    pub amount: Uint128,
+}
 
 #[cw_serde]
 pub struct Bid {
@@ -8,6 +28,158 @@ pub struct Bid {
     pub bidder: Addr,
     /// Amount of tokens bid in *10^6 format
     pub amount: Uint128,
+    /// Breakdown of the bid amount by the addresses that paid for it
+    pub payers: Vec<PayerShare>,
+    /// Time of the first contribution to the bid
+    pub first_bid_at: Timestamp,
+    /// Time of the latest contribution to the bid
+    pub last_bid_at: Timestamp,
+    /// Hash of the terms of sale acknowledged with the latest contribution, if the
+    /// bidding period has terms
+    pub terms_hash: Option<String>,
+}
+
+impl Bid {
+    /// Records `amount` paid into the bid by `payer`
+    pub fn add_payment(&mut self, payer: &Addr, amount: Uint128) {
+        self.amount += amount;
+        match self.payers.iter_mut().find(|share| &share.payer == payer) {
+            Some(share) => share.amount += amount,
+            None => self.payers.push(PayerShare {
+                payer: payer.clone(),
+                amount,
+            }),
+        }
+    }
+
+    /// Merges `other` into the bid, keeping the payers of both
+    pub fn merge(&mut self, other: Bid) {
+        for share in other.payers {
+            self.add_payment(&share.payer, share.amount);
+        }
+        self.first_bid_at = self.first_bid_at.min(other.first_bid_at);
+        self.last_bid_at = self.last_bid_at.max(other.last_bid_at);
+        if self.terms_hash.is_none() {
+            self.terms_hash = other.terms_hash;
+        }
+    }
+
+    /// Payouts refunding the bid to `refund_to`
+    pub fn refunds(&self, refund_to: &RefundTarget) -> Vec<Payout> {
+        match refund_to {
+            RefundTarget::Beneficiary => vec![Payout {
+                address: self.bidder.clone(),
+                amount: self.amount,
+            }],
+            RefundTarget::Payer => self
+                .payers
+                .iter()
+                .map(|share| Payout {
+                    address: share.payer.clone(),
+                    amount: share.amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Contribution {
+    /// Position of the contribution in the bidder's history
+    pub index: u64,
+    /// Bidding period the contribution was made in
+    pub period_id: u64,
+    /// Address that paid the contribution
+    pub payer: Addr,
+    /// Amount of tokens contributed
+    pub amount: Uint128,
+    /// Block time of the contribution
+    pub time: Timestamp,
+    /// Block height of the contribution
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct RankedBid {
+    /// Address of the bidder
+    pub bidder: Addr,
+    /// Amount of tokens bid
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PeriodStats {
+    /// Total amount of tokens held in bids
+    pub total_deposited: Uint128,
+    /// Number of bidders
+    pub bidder_count: u64,
+    /// Highest bid
+    pub highest_bid: Option<RankedBid>,
+    /// Lowest bid that would be accepted if the `accepted_bidders` highest bids were,
+    /// or `None` if there are fewer bids than `accepted_bidders`
+    pub cutoff_bid: Option<RankedBid>,
+}
+
+#[cw_serde]
+pub struct ProceedsShare {
+    /// Address receiving the share
+    pub address: Addr,
+    /// Part of the proceeds paid to the address, in basis points
+    pub bps: u64,
+}
+
+#[cw_serde]
+pub struct Payout {
+    /// Address receiving the funds
+    pub address: Addr,
+    /// Amount of tokens sent
+    pub amount: Uint128,
+}
+
+/// Who receives the funds of a refunded bid
+#[cw_serde]
+#[derive(Default)]
+pub enum RefundTarget {
+    /// The bidder the bid was made for
+    #[default]
+    Beneficiary,
+    /// Each address that paid into the bid, in proportion to what it paid
+    Payer,
+}
+
+#[cw_serde]
+pub struct GroupRestriction {
+    /// Address of the cw4 group contract whose members are allowed to bid
+    pub contract: Addr,
+    /// Optional maximum bid per unit of membership weight
+    /// A member's bid may not exceed `cap_per_weight * weight`
+    pub cap_per_weight: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct DisputeWindow {
+    /// Seconds during which the proceeds of an ended bidding period are held
+    pub duration: u64,
+    /// Address that can veto the result of the bidding period during the window
+    pub guardian: Addr,
+}
+
+/// Presentation and terms of sale of a bidding period
+#[cw_serde]
+pub struct PeriodMetadata {
+    /// URI of the image representing the sale
+    pub image: Option<String>,
+    /// External links, such as the sale's website
+    #[serde(default)]
+    pub links: Vec<String>,
+    /// Category of the sale, up to 32 characters long
+    pub category: Option<String>,
+    /// At most 10 tags describing the sale, each up to 32 characters long
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Hex-encoded SHA-256 hash of the terms of sale, which bidders must acknowledge
+    pub terms_hash: Option<String>,
 }
 
 #[cw_serde]
@@ -16,16 +188,275 @@ pub struct BiddingPeriod {
     pub name: String,
     /// Optional description string
     pub description: Option<String>,
-    /// Expiry time for the bidding period
-    /// Bids made beyond this timestamp will not be accepted
-    pub expires_at: Timestamp,
+    /// Optional time or height from which bids are accepted
+    /// Bids are accepted as soon as the bidding period starts if it is not set
+    pub starts_at: Option<Scheduled>,
+    /// Expiry time or height for the bidding period
+    /// Bids made beyond this point will not be accepted
+    pub expires_at: Expiration,
     /// Minimum bid amount
     pub minimum_bid: Uint128,
     /// Amount of bids that can be accepted once the bidding period is ended
     pub accepted_bidders: u64,
     /// Denomination in which bids are to be made
     pub denom: String,
+    /// Optional maximum total bid a single address can hold
+    pub max_bid_per_address: Option<Uint128>,
+    /// Optional maximum number of distinct bidders
+    pub max_bidders: Option<u64>,
+    /// Optional minimum amount by which an existing bid can be topped up
+    pub min_increment: Option<Uint128>,
+    /// Whether refunds go to the bidder or to the addresses that paid for the bid
+    #[serde(default)]
+    pub refund_to: RefundTarget,
+    /// Optional cw4 group restricting bids to its members
+    pub group: Option<GroupRestriction>,
+    /// Optional public key whose signed attestation is required to bid
+    pub attestor: Option<Attestor>,
+    /// Optional window during which the result can be vetoed before the proceeds are released
+    pub dispute: Option<DisputeWindow>,
+    /// Optional cw721 collection, minted by this contract, in which winners can claim receipts
+    pub receipt_collection: Option<Addr>,
+    /// Optional image, links, category, tags and terms of sale
+    pub metadata: Option<PeriodMetadata>,
+    /// Whether bidders can transfer their bid to another address
+    #[serde(default)]
+    pub transferable_bids: bool,
+}
+
+/// Series of bidding periods derived from a template, started permissionlessly one after the other
+#[cw_serde]
+pub struct Schedule {
+    /// Configuration of the derived bidding periods. Its `starts_at` and `expires_at` are ignored
+    pub template: BiddingPeriod,
+    /// Time or number of blocks between the start and the expiry of each derived bidding period
+    pub interval: Duration,
+    /// Number of bidding periods in the series
+    pub count: u64,
+    /// Number of bidding periods started so far
+    pub started: u64,
+}
+
+impl Schedule {
+    /// Configuration of the next bidding period of the series, starting at `block`
+    pub fn next_period(&self, block: &BlockInfo) -> BiddingPeriod {
+        BiddingPeriod {
+            name: format!("{} #{}", self.template.name, self.started + 1),
+            starts_at: None,
+            expires_at: self.interval.after(block),
+            ..self.template.clone()
+        }
+    }
+}
+
+/// Result of an ended bidding period whose proceeds are held during its dispute window
+#[cw_serde]
+pub struct PendingSettlement {
+    /// Sequence number of the ended bidding period
+    pub period_id: u64,
+    /// Name of the ended bidding period
+    pub period_name: String,
+    /// Accepted bids, refunded if the result is vetoed
+    pub winners: Vec<Bid>,
+    /// Total amount of the accepted bids
+    pub proceeds: Uint128,
+    /// Denomination of the bids
+    pub denom: String,
+    /// Address the proceeds are released to, after paying the shares of `split`
+    pub withdrawal_address: Addr,
+    /// Shares of the proceeds paid to other addresses, as set when the bidding period ended
+    #[serde(default)]
+    pub split: Vec<ProceedsShare>,
+    /// Who receives the refunds if the result is vetoed
+    pub refund_to: RefundTarget,
+    /// Address that can veto the result
+    pub guardian: Addr,
+    /// Time from which the proceeds can be released
+    pub releasable_at: Timestamp,
+    /// Collection in which the winners can claim receipts once the proceeds are released
+    pub receipt_collection: Option<Addr>,
 }
 
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Time at which the contract was paused, if it is paused
+pub const PAUSED_AT: Item<Timestamp> = Item::new("paused_at");
 pub const BIDS: Map<&Addr, Bid> = Map::new("bids");
 pub const BIDDING_PERIOD: Item<BiddingPeriod> = Item::new("bidding_period");
+/// Schedule of the upcoming bidding periods, removed once all of them have started
+pub const SCHEDULE: Item<Schedule> = Item::new("schedule");
+/// Default withdrawal address of the proceeds, set by a treasurer
+pub const TREASURY: Item<Addr> = Item::new("treasury");
+/// Shares of the proceeds paid to other addresses than the withdrawal address, set by a treasurer
+pub const PROCEEDS_SPLIT: Item<Vec<ProceedsShare>> = Item::new("proceeds_split");
+pub const PENDING_SETTLEMENT: Item<PendingSettlement> = Item::new("pending_settlement");
+/// Sequence number of the latest bidding period
+pub const PERIOD_ID: Item<u64> = Item::new("period_id");
+/// Bids ordered by amount, ties being ordered by address
+pub const BID_RANKING: Map<(u128, &Addr), Empty> = Map::new("bid_ranking");
+/// Running statistics of the current bidding period
+pub const STATS: Item<PeriodStats> = Item::new("stats");
+/// Append-only log of the contributions made to each bidder's bids
+pub const BID_HISTORY: Map<(&Addr, u64), Contribution> = Map::new("bid_history");
+/// Addresses that can neither bid nor hold a bid
+pub const DENYLIST: Map<&Addr, Empty> = Map::new("denylist");
+/// Attestation nonces already used by each bidder
+pub const USED_NONCES: Map<(&Addr, u64), Empty> = Map::new("used_nonces");
+
+/// Operators approved by a bidder to manage their bid, keyed by (bidder, operator)
+pub const OPERATORS: Map<(&Addr, &Addr), Expiration> = Map::new("operators");
+
+/// returns true if the contract is paused
+pub fn is_paused(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(PAUSED_AT.may_load(storage)?.is_some())
+}
+
+/// returns true if `sender` is `owner`, or an operator approved by `owner` whose approval has not expired
+pub fn can_manage_bid(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    owner: &Addr,
+    sender: &Addr,
+) -> StdResult<bool> {
+    if owner == sender {
+        return Ok(true);
+    }
+    let expires = OPERATORS.may_load(storage, (owner, sender))?;
+    Ok(expires.is_some_and(|expires| !expires.is_expired(block)))
+}
+
+/// Appends a contribution to the bidder's history, assigning it the next index
+pub fn append_contribution(
+    storage: &mut dyn Storage,
+    bidder: &Addr,
+    mut contribution: Contribution,
+) -> StdResult<()> {
+    let last = BID_HISTORY
+        .prefix(bidder)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    contribution.index = last.map_or(0, |index| index + 1);
+    BID_HISTORY.save(storage, (bidder, contribution.index), &contribution)
+}
+
+/// Saves a bid, keeping the bid ranking and period statistics up to date
+pub fn save_bid(storage: &mut dyn Storage, bid: &Bid, accepted_bidders: u64) -> StdResult<()> {
+    let mut stats = STATS.may_load(storage)?.unwrap_or_default();
+    match BIDS.may_load(storage, &bid.bidder)? {
+        Some(previous) => {
+            BID_RANKING.remove(storage, (previous.amount.u128(), &bid.bidder));
+            stats.total_deposited -= previous.amount;
+            stats.cutoff_bid = cutoff_after_removal(
+                storage,
+                stats.cutoff_bid,
+                &ranked_bid(&previous.bidder, previous.amount),
+            )?;
+        }
+        None => stats.bidder_count += 1,
+    }
+    stats.total_deposited += bid.amount;
+
+    BIDS.save(storage, &bid.bidder, bid)?;
+    BID_RANKING.save(storage, (bid.amount.u128(), &bid.bidder), &Empty {})?;
+    stats.cutoff_bid = match stats.cutoff_bid {
+        Some(cutoff) if rank(&ranked_bid(&bid.bidder, bid.amount)) > rank(&cutoff) => {
+            next_ranked_bid(storage, Some(&cutoff), Order::Ascending)?
+        }
+        Some(cutoff) => Some(cutoff),
+        // The ranking just reached `accepted_bidders` bids, so the cut-off is the lowest one
+        None if accepted_bidders > 0 && stats.bidder_count == accepted_bidders => {
+            next_ranked_bid(storage, None, Order::Ascending)?
+        }
+        None => None,
+    };
+    stats.highest_bid = next_ranked_bid(storage, None, Order::Descending)?;
+    STATS.save(storage, &stats)
+}
+
+/// Removes a bid, keeping the bid ranking and period statistics up to date
+pub fn remove_bid(storage: &mut dyn Storage, address: &Addr) -> StdResult<Option<Bid>> {
+    let bid = match BIDS.may_load(storage, address)? {
+        Some(bid) => bid,
+        None => return Ok(None),
+    };
+    let mut stats = STATS.may_load(storage)?.unwrap_or_default();
+    stats.total_deposited -= bid.amount;
+    stats.bidder_count -= 1;
+
+    BIDS.remove(storage, address);
+    BID_RANKING.remove(storage, (bid.amount.u128(), address));
+    stats.cutoff_bid = cutoff_after_removal(
+        storage,
+        stats.cutoff_bid,
+        &ranked_bid(&bid.bidder, bid.amount),
+    )?;
+    stats.highest_bid = next_ranked_bid(storage, None, Order::Descending)?;
+    STATS.save(storage, &stats)?;
+    Ok(Some(bid))
+}
+
+/// Removes all bids along with their ranking and statistics
+pub fn clear_bids(storage: &mut dyn Storage) {
+    BIDS.clear(storage);
+    BID_RANKING.clear(storage);
+    STATS.remove(storage);
+}
+
+/// Recomputes the cut-off bid of the period statistics for a new number of accepted bids
+pub fn refresh_cutoff(storage: &mut dyn Storage, accepted_bidders: u64) -> StdResult<()> {
+    let mut stats = STATS.may_load(storage)?.unwrap_or_default();
+    stats.cutoff_bid = match accepted_bidders {
+        0 => None,
+        n => BID_RANKING
+            .keys(storage, None, None, Order::Descending)
+            .nth(n as usize - 1)
+            .transpose()?
+            .map(|(amount, bidder)| ranked_bid(&bidder, Uint128::new(amount))),
+    };
+    STATS.save(storage, &stats)
+}
+
+/// Moves the cut-off bid to the next lower bid of the ranking if `removed`, which has
+/// already left the ranking, was at or above it
+fn cutoff_after_removal(
+    storage: &dyn Storage,
+    cutoff: Option<RankedBid>,
+    removed: &RankedBid,
+) -> StdResult<Option<RankedBid>> {
+    match cutoff {
+        Some(cutoff) if rank(removed) >= rank(&cutoff) => {
+            next_ranked_bid(storage, Some(&cutoff), Order::Descending)
+        }
+        cutoff => Ok(cutoff),
+    }
+}
+
+/// Returns the first bid of the ranking in `order`, strictly past `after` if given
+fn next_ranked_bid(
+    storage: &dyn Storage,
+    after: Option<&RankedBid>,
+    order: Order,
+) -> StdResult<Option<RankedBid>> {
+    let bound = after.map(|bid| Bound::exclusive(rank(bid)));
+    let (min, max) = match order {
+        Order::Ascending => (bound, None),
+        Order::Descending => (None, bound),
+    };
+    let key = BID_RANKING
+        .keys(storage, min, max, order)
+        .next()
+        .transpose()?;
+    Ok(key.map(|(amount, bidder)| ranked_bid(&bidder, Uint128::new(amount))))
+}
+
+/// Position of a bid in the bid ranking
+fn rank(bid: &RankedBid) -> (u128, &Addr) {
+    (bid.amount.u128(), &bid.bidder)
+}
+
+fn ranked_bid(bidder: &Addr, amount: Uint128) -> RankedBid {
+    RankedBid {
+        bidder: bidder.clone(),
+        amount,
+    }
+}
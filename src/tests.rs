@@ -1,21 +1,142 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
+    use crate::admins::{AdminList, ADMINS};
     use crate::helpers::JunoBidContract;
-    use crate::msg::InstantiateMsg;
-    use cosmwasm_std::{Addr, Coin, Empty, Uint128};
-    use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
+    use crate::hooks::BidHookExecuteMsg;
+    use crate::migrations::v0_1;
+    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::receipts::Cw721ExecuteMsg;
+    use crate::state::{BiddingPeriod, RefundTarget};
+    use anyhow::Result as AnyResult;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        testing::mock_env, to_binary, Addr, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+        MessageInfo, Order, Response, StdError, StdResult, Uint128, WasmMsg,
+    };
+    use cw2::set_contract_version;
+    use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+    use cw_storage_plus::{Item, Map};
+    use cw_utils::{Duration, Expiration, Scheduled};
 
     pub fn contract() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new(
             crate::contract::execute,
             crate::contract::instantiate,
             crate::contract::query,
+        )
+        .with_migrate(crate::contract::migrate)
+        .with_sudo(crate::contract::sudo)
+        .with_reply(crate::contract::reply);
+        Box::new(contract)
+    }
+
+    /// Hook contract counting the notifications it receives, or failing on every
+    /// notification when instantiated with `fail` set
+    pub fn contract_hook() -> Box<dyn Contract<Empty>> {
+        const FAIL: Item<bool> = Item::new("fail");
+        const COUNT: Item<u64> = Item::new("count");
+        let contract = ContractWrapper::new(
+            |deps: DepsMut, _: Env, _: MessageInfo, _: BidHookExecuteMsg| -> StdResult<Response> {
+                if FAIL.load(deps.storage)? {
+                    return Err(StdError::generic_err("hook failed"));
+                }
+                COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+                Ok(Response::new())
+            },
+            |deps: DepsMut, _: Env, _: MessageInfo, fail: bool| -> StdResult<Response> {
+                FAIL.save(deps.storage, &fail)?;
+                COUNT.save(deps.storage, &0)?;
+                Ok(Response::new())
+            },
+            |deps: Deps, _: Env, _: Empty| to_binary(&COUNT.load(deps.storage)?),
+        );
+        Box::new(contract)
+    }
+
+    /// cw721 collection recording the owner of each minted token
+    pub fn contract_cw721() -> Box<dyn Contract<Empty>> {
+        const TOKENS: Map<String, String> = Map::new("tokens");
+        let contract = ContractWrapper::new(
+            |deps: DepsMut, _: Env, _: MessageInfo, msg: Cw721ExecuteMsg| -> StdResult<Response> {
+                let Cw721ExecuteMsg::Mint {
+                    token_id, owner, ..
+                } = msg;
+                TOKENS.save(deps.storage, token_id, &owner)?;
+                Ok(Response::new())
+            },
+            |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+                Ok(Response::new())
+            },
+            |deps: Deps, _: Env, _: Empty| {
+                let tokens = TOKENS
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<_>>>()?;
+                to_binary(&tokens)
+            },
+        );
+        Box::new(contract)
+    }
+
+    #[cw_serde]
+    pub struct LegacyInstantiateMsg {
+        pub contract: String,
+        pub version: String,
+    }
+
+    /// Contract writing the v0.1 state layout on instantiation, used to test migrations
+    pub fn contract_legacy() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+                Ok(Response::new())
+            },
+            |deps: DepsMut, env: Env, _: MessageInfo, msg: LegacyInstantiateMsg| {
+                set_contract_version(deps.storage, msg.contract, msg.version)?;
+                ADMINS.save(
+                    deps.storage,
+                    &AdminList {
+                        admins: vec![Addr::unchecked(ADMIN)],
+                        threshold: 1,
+                    },
+                )?;
+                v0_1::BIDDING_PERIOD.save(
+                    deps.storage,
+                    &v0_1::BiddingPeriod {
+                        name: "My Bidding Period".into(),
+                        description: None,
+                        expires_at: env.block.time.plus_seconds(1440),
+                        minimum_bid: Uint128::new(500),
+                        accepted_bidders: 1,
+                        denom: NATIVE_DENOM.into(),
+                    },
+                )?;
+                v0_1::BIDS.save(
+                    deps.storage,
+                    &Addr::unchecked(BIDDER),
+                    &v0_1::Bid {
+                        bidder: Addr::unchecked(BIDDER),
+                        amount: Uint128::new(1000),
+                    },
+                )?;
+                StdResult::Ok(Response::new())
+            },
+            |_: Deps, _: Env, _: Empty| to_binary(&Empty {}),
+        );
+        Box::new(contract)
+    }
+
+    pub fn contract_cw4_group() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(
+            cw4_group::contract::execute,
+            cw4_group::contract::instantiate,
+            cw4_group::contract::query,
         );
         Box::new(contract)
     }
 
     const BIDDER: &str = "juno1bidder";
     const OTHER_BIDDER: &str = "juno1otherbidder";
+    const THIRD_BIDDER: &str = "juno1thirdbidder";
     const ADMIN: &str = "juno1admin";
     const NATIVE_DENOM: &str = "ujunox";
 
@@ -41,6 +162,8 @@ mod tests {
 
         let msg = InstantiateMsg {
             admins: vec![Addr::unchecked(ADMIN)],
+            threshold: None,
+            emergency_withdraw_delay: None,
         };
 
         let juno_bid_contract_addr = app
@@ -52,27 +175,104 @@ mod tests {
         (app, juno_bid_contract)
     }
 
+    /// Default bidding period configuration, expiring in 24 hours
+    fn bidding_period() -> BiddingPeriod {
+        let env = mock_env();
+
+        BiddingPeriod {
+            name: "My Bidding Period".into(),
+            description: None,
+            starts_at: None,
+            expires_at: Expiration::AtTime(env.block.time.plus_seconds(1440)),
+            minimum_bid: Uint128::new(500),
+            accepted_bidders: 1,
+            denom: NATIVE_DENOM.into(),
+            max_bid_per_address: None,
+            max_bidders: None,
+            min_increment: None,
+            refund_to: RefundTarget::Beneficiary,
+            group: None,
+            attestor: None,
+            dispute: None,
+            receipt_collection: None,
+            metadata: None,
+            transferable_bids: false,
+        }
+    }
+
+    fn start_bidding(app: &mut App, juno_bid_contract: &JunoBidContract, config: BiddingPeriod) {
+        let msg = ExecuteMsg::StartBidding { config };
+        app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+            .unwrap();
+    }
+
+    fn bid(
+        app: &mut App,
+        juno_bid_contract: &JunoBidContract,
+        sender: &str,
+        address: Option<&str>,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::Bid {
+            address: address.map(Addr::unchecked),
+            attestation: None,
+            terms_hash: None,
+        };
+        execute_with_funds(app, juno_bid_contract, sender, msg, amount)
+    }
+
+    fn execute_with_funds(
+        app: &mut App,
+        juno_bid_contract: &JunoBidContract,
+        sender: &str,
+        msg: ExecuteMsg,
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        let juno_msg: CosmosMsg = WasmMsg::Execute {
+            contract_addr: juno_bid_contract.addr().into(),
+            msg: to_binary(&msg).unwrap(),
+            funds: vec![Coin {
+                denom: NATIVE_DENOM.to_string(),
+                amount: Uint128::new(amount),
+            }],
+        }
+        .into();
+
+        app.execute(Addr::unchecked(sender), juno_msg)
+    }
+
     mod tests {
-        use cosmwasm_std::{coin, testing::mock_env, to_binary, CosmosMsg, Timestamp, WasmMsg};
+        use cosmwasm_std::{coin, to_binary, Binary, CosmosMsg, Event, WasmMsg};
+        use cw4::Member;
+        use k256::ecdsa::signature::Signer;
+        use k256::ecdsa::{Signature, SigningKey};
 
         use super::*;
-        use crate::{msg::ExecuteMsg, state::BiddingPeriod};
+        use crate::admins::{AdminTransfer, Role};
+        use crate::attestation::{Attestation, AttestationPayload, Attestor};
+        use crate::hooks::HookFailure;
+        use crate::msg::{
+            BidHistoryResponse, BidResponse, BiddingPeriodResponse, DenylistResponse, ExecuteMsg,
+            HooksResponse, MigrateMsg, OperatorInfo, OperatorsResponse, PauseStatusResponse,
+            PendingAdminTransferResponse, PendingSettlementResponse, ProposalResponse,
+            ProposalsResponse, QueryMsg, ReceiptResponse, RolesResponse, ScheduleResponse,
+            SimulateBidResponse, SimulateEndBiddingResponse, StatsResponse, SudoMsg,
+            TreasuryResponse,
+        };
+        use crate::proposals::ProposalAction;
+        use crate::state::{
+            Contribution, DisputeWindow, GroupRestriction, PayerShare, Payout, PeriodMetadata,
+            PeriodStats, ProceedsShare, RankedBid,
+        };
+        use crate::ContractError;
 
         #[test]
         fn try_start_bidding() {
             let (mut app, juno_bid_contract) = proper_instantiate();
-            let env = mock_env();
 
             // Create a bidding period
             let msg = ExecuteMsg::StartBidding {
-                config: BiddingPeriod {
-                    name: "My Bidding Period".into(),
-                    description: None,
-                    expires_at: Timestamp::from_seconds(env.block.time.seconds() + 1440), // Expires in 24 hours,
-                    minimum_bid: Uint128::new(500),
-                    accepted_bidders: 1,
-                    denom: NATIVE_DENOM.into(),
-                },
+                config: bidding_period(),
             };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
@@ -87,26 +287,18 @@ mod tests {
         #[test]
         fn try_end_bidding() {
             let (mut app, juno_bid_contract) = proper_instantiate();
-            let env = mock_env();
 
             // Give OTHER_BIDDER 500 ujunox
             app.send_tokens(
                 Addr::unchecked(BIDDER),
                 Addr::unchecked(OTHER_BIDDER),
-                &vec![coin(500, NATIVE_DENOM)],
+                &[coin(500, NATIVE_DENOM)],
             )
             .unwrap();
 
             // Create a bidding period
             let msg = ExecuteMsg::StartBidding {
-                config: BiddingPeriod {
-                    name: "My Bidding Period".into(),
-                    description: None,
-                    expires_at: Timestamp::from_seconds(env.block.time.seconds() + 1440), // Expires in 24 hours,
-                    minimum_bid: Uint128::new(500),
-                    accepted_bidders: 1,
-                    denom: NATIVE_DENOM.into(),
-                },
+                config: bidding_period(),
             };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
@@ -118,7 +310,11 @@ mod tests {
             app.execute(Addr::unchecked(ADMIN), juno_msg).unwrap();
 
             // Create a bid for BIDDER
-            let msg = ExecuteMsg::Bid { address: None };
+            let msg = ExecuteMsg::Bid {
+                address: None,
+                attestation: None,
+                terms_hash: None,
+            };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
                 msg: to_binary(&msg).unwrap(),
@@ -132,7 +328,11 @@ mod tests {
             app.execute(Addr::unchecked(BIDDER), juno_msg).unwrap();
 
             // Create a bid for OTHER_BIDDER
-            let msg = ExecuteMsg::Bid { address: None };
+            let msg = ExecuteMsg::Bid {
+                address: None,
+                attestation: None,
+                terms_hash: None,
+            };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
                 msg: to_binary(&msg).unwrap(),
@@ -184,18 +384,10 @@ mod tests {
         #[test]
         fn try_bid() {
             let (mut app, juno_bid_contract) = proper_instantiate();
-            let env = mock_env();
 
             // Create a bidding period
             let msg = ExecuteMsg::StartBidding {
-                config: BiddingPeriod {
-                    name: "My Bidding Period".into(),
-                    description: None,
-                    expires_at: Timestamp::from_seconds(env.block.time.seconds() + 1440), // Expires in 24 hours,
-                    minimum_bid: Uint128::new(500),
-                    accepted_bidders: 1,
-                    denom: NATIVE_DENOM.into(),
-                },
+                config: bidding_period(),
             };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
@@ -207,7 +399,11 @@ mod tests {
             app.execute(Addr::unchecked(ADMIN), juno_msg).unwrap();
 
             // Create a bid
-            let msg = ExecuteMsg::Bid { address: None };
+            let msg = ExecuteMsg::Bid {
+                address: None,
+                attestation: None,
+                terms_hash: None,
+            };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
                 msg: to_binary(&msg).unwrap(),
@@ -224,18 +420,10 @@ mod tests {
         #[test]
         fn try_proxy_bid() {
             let (mut app, juno_bid_contract) = proper_instantiate();
-            let env = mock_env();
 
             // Create a bidding period
             let msg = ExecuteMsg::StartBidding {
-                config: BiddingPeriod {
-                    name: "My Bidding Period".into(),
-                    description: None,
-                    expires_at: Timestamp::from_seconds(env.block.time.seconds() + 1440), // Expires in 24 hours,
-                    minimum_bid: Uint128::new(500),
-                    accepted_bidders: 1,
-                    denom: NATIVE_DENOM.into(),
-                },
+                config: bidding_period(),
             };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
@@ -249,6 +437,8 @@ mod tests {
             // Create a proxy bid
             let msg = ExecuteMsg::Bid {
                 address: Some(Addr::unchecked(BIDDER)),
+                attestation: None,
+                terms_hash: None,
             };
             let juno_msg: CosmosMsg = WasmMsg::Execute {
                 contract_addr: juno_bid_contract.addr().into(),
@@ -262,5 +452,2755 @@ mod tests {
 
             app.execute(Addr::unchecked(BIDDER), juno_msg).unwrap();
         }
+
+        #[test]
+        fn try_group_gated_bid() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            // Create a cw4 group with BIDDER as its only member
+            let group_id = app.store_code(contract_cw4_group());
+            let group_addr = app
+                .instantiate_contract(
+                    group_id,
+                    Addr::unchecked(ADMIN),
+                    &cw4_group::msg::InstantiateMsg {
+                        admin: None,
+                        members: vec![Member {
+                            addr: BIDDER.into(),
+                            weight: 2,
+                        }],
+                    },
+                    &[],
+                    "group",
+                    None,
+                )
+                .unwrap();
+
+            // Create a bidding period gated by the group, capped at 600 per unit of weight
+            let config = BiddingPeriod {
+                group: Some(GroupRestriction {
+                    contract: group_addr,
+                    cap_per_weight: Some(Uint128::new(600)),
+                }),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            // Bidding on behalf of a non-member fails
+            let err = bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                500,
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NotGroupMember { .. }
+            ));
+
+            // A member can bid up to their cap of 1200
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::MemberCapExceeded { cap } if cap == Uint128::new(1200)
+            ));
+        }
+
+        #[test]
+        fn try_attested_bid() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let env = mock_env();
+
+            // Create a bidding period requiring attestations
+            let signing_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+            let pubkey = signing_key.verifying_key().to_bytes();
+            let config = BiddingPeriod {
+                attestor: Some(Attestor::Secp256k1 {
+                    pubkey: Binary::from(pubkey.as_slice()),
+                }),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            // Bidding without an attestation fails
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::AttestationRequired {}
+            ));
+
+            // Sign an attestation for BIDDER
+            let payload = AttestationPayload {
+                contract: juno_bid_contract.addr(),
+                period_id: 1,
+                bidder: Addr::unchecked(BIDDER),
+                max_amount: Uint128::new(1000),
+                expiry: env.block.time.plus_seconds(60),
+                nonce: 1,
+            };
+            let signature: Signature = signing_key.sign(&payload.to_bytes().unwrap());
+            let attestation = Attestation {
+                max_amount: payload.max_amount,
+                expiry: payload.expiry,
+                nonce: payload.nonce,
+                signature: Binary::from(signature.as_ref()),
+            };
+
+            // An attestation for another bidder is rejected
+            let msg = ExecuteMsg::Bid {
+                address: Some(Addr::unchecked(OTHER_BIDDER)),
+                attestation: Some(attestation.clone()),
+                terms_hash: None,
+            };
+            let err =
+                execute_with_funds(&mut app, &juno_bid_contract, BIDDER, msg, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::InvalidAttestation {}
+            ));
+
+            let msg = ExecuteMsg::Bid {
+                address: None,
+                attestation: Some(attestation),
+                terms_hash: None,
+            };
+            // The attestation authorizes BIDDER, but cannot be replayed
+            execute_with_funds(&mut app, &juno_bid_contract, BIDDER, msg.clone(), 500).unwrap();
+            let err =
+                execute_with_funds(&mut app, &juno_bid_contract, BIDDER, msg, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NonceAlreadyUsed { nonce: 1 }
+            ));
+        }
+
+        #[test]
+        fn try_bid_limits() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            // Give OTHER_BIDDER 500 ujunox
+            app.send_tokens(
+                Addr::unchecked(BIDDER),
+                Addr::unchecked(OTHER_BIDDER),
+                &[coin(500, NATIVE_DENOM)],
+            )
+            .unwrap();
+
+            // Create a bidding period with a single bidder, bids of at most 800
+            // and top-ups of at least 200
+            let config = BiddingPeriod {
+                minimum_bid: Uint128::new(100),
+                max_bid_per_address: Some(Uint128::new(800)),
+                max_bidders: Some(1),
+                min_increment: Some(Uint128::new(200)),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+
+            // The top-up is below the minimum increment
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 100).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::IncrementTooLow { .. }
+            ));
+
+            // The top-up would exceed the maximum bid
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 400).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::MaxBidExceeded { .. }
+            ));
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 300).unwrap();
+
+            // There is no room for another bidder
+            let err = bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::MaxBiddersReached { max: 1 }
+            ));
+        }
+
+        #[test]
+        fn try_disqualify_bid() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            // Give OTHER_BIDDER 500 ujunox
+            app.send_tokens(
+                Addr::unchecked(BIDDER),
+                Addr::unchecked(OTHER_BIDDER),
+                &[coin(500, NATIVE_DENOM)],
+            )
+            .unwrap();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 500).unwrap();
+
+            // Only admins can disqualify bids
+            let msg = ExecuteMsg::DisqualifyBid {
+                address: Addr::unchecked(OTHER_BIDDER),
+                reason: "Sanctioned address".into(),
+                denylist: true,
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+
+            // Disqualify OTHER_BIDDER and verify that they have been refunded 500 ujunox
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(OTHER_BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(500));
+
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(OTHER_BIDDER),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.bid, None);
+
+            let res: DenylistResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Denylist {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.addresses, vec![Addr::unchecked(OTHER_BIDDER)]);
+
+            // OTHER_BIDDER can neither bid nor receive a bid
+            let err = bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Denylisted { .. }
+            ));
+            let err = bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                500,
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Denylisted { .. }
+            ));
+
+            // Only owners can update the denylist, including addresses without a bid
+            let msg = ExecuteMsg::UpdateDenylist {
+                add: vec![Addr::unchecked(THIRD_BIDDER)],
+                remove: vec![Addr::unchecked(OTHER_BIDDER)],
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let res: DenylistResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Denylist {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.addresses, vec![Addr::unchecked(THIRD_BIDDER)]);
+
+            // OTHER_BIDDER can be bid on behalf of again, unlike THIRD_BIDDER
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                500,
+            )
+            .unwrap();
+            let err = bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(THIRD_BIDDER),
+                500,
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Denylisted { .. }
+            ));
+        }
+
+        #[test]
+        fn try_payer_refund() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            // Create a bidding period refunding the payers of each bid
+            let config = BiddingPeriod {
+                refund_to: RefundTarget::Payer,
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            // BIDDER pays for a bid on behalf of OTHER_BIDDER
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                1000,
+            )
+            .unwrap();
+
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(OTHER_BIDDER),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.bid.unwrap().payers,
+                vec![PayerShare {
+                    payer: Addr::unchecked(BIDDER),
+                    amount: Uint128::new(1000),
+                }]
+            );
+
+            // BIDDER also bids for themselves, and only that bid is accepted
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            // Verify that BIDDER, who paid for the rejected bid, has been refunded 1000 ujunox
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1000));
+
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(OTHER_BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::zero());
+        }
+
+        #[test]
+        fn try_migrate() {
+            let mut app = mock_app();
+            let legacy_id = app.store_code(contract_legacy());
+            let juno_bid_id = app.store_code(contract());
+
+            let instantiate_legacy = |app: &mut App, contract: &str, version: &str| {
+                let msg = LegacyInstantiateMsg {
+                    contract: contract.into(),
+                    version: version.into(),
+                };
+                app.instantiate_contract(
+                    legacy_id,
+                    Addr::unchecked(ADMIN),
+                    &msg,
+                    &[],
+                    "legacy",
+                    Some(ADMIN.into()),
+                )
+                .unwrap()
+            };
+
+            // Migrating a different contract fails
+            let addr = instantiate_legacy(&mut app, "crates.io:other", "0.1.0");
+            let err = app
+                .migrate_contract(Addr::unchecked(ADMIN), addr, &MigrateMsg {}, juno_bid_id)
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::CannotMigrate { .. }
+            ));
+
+            // Downgrading fails
+            let addr = instantiate_legacy(&mut app, "crates.io:juno_bid", "99.0.0");
+            let err = app
+                .migrate_contract(Addr::unchecked(ADMIN), addr, &MigrateMsg {}, juno_bid_id)
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::CannotMigrateVersion { .. }
+            ));
+
+            // Migrating from v0.1.0 upgrades the bidding period and bids
+            let addr = instantiate_legacy(&mut app, "crates.io:juno_bid", "0.1.0");
+            app.migrate_contract(
+                Addr::unchecked(ADMIN),
+                addr.clone(),
+                &MigrateMsg {},
+                juno_bid_id,
+            )
+            .unwrap();
+
+            let version = cw2::query_contract_info(&app, addr.clone()).unwrap();
+            assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(addr.clone(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.period_id, Some(1));
+            assert_eq!(
+                res.bidding_period.unwrap().refund_to,
+                RefundTarget::Beneficiary
+            );
+
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    addr,
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(BIDDER),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.bid.unwrap().payers,
+                vec![PayerShare {
+                    payer: Addr::unchecked(BIDDER),
+                    amount: Uint128::new(1000),
+                }]
+            );
+        }
+
+        #[test]
+        fn try_bid_history() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+
+            // BIDDER bids, then tops up their bid a block later
+            let first_block = app.block_info();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            app.update_block(|block| {
+                block.time = block.time.plus_seconds(5);
+                block.height += 1;
+            });
+            let second_block = app.block_info();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 600).unwrap();
+
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(BIDDER),
+                    },
+                )
+                .unwrap();
+            let bid = res.bid.unwrap();
+            assert_eq!(bid.amount, Uint128::new(1100));
+            assert_eq!(bid.first_bid_at, first_block.time);
+            assert_eq!(bid.last_bid_at, second_block.time);
+
+            // Query the history one contribution at a time
+            let res: BidHistoryResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::BidHistory {
+                        address: Addr::unchecked(BIDDER),
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.contributions,
+                vec![Contribution {
+                    index: 0,
+                    period_id: 1,
+                    payer: Addr::unchecked(BIDDER),
+                    amount: Uint128::new(500),
+                    time: first_block.time,
+                    height: first_block.height,
+                }]
+            );
+
+            let res: BidHistoryResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::BidHistory {
+                        address: Addr::unchecked(BIDDER),
+                        start_after: Some(0),
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.contributions,
+                vec![Contribution {
+                    index: 1,
+                    period_id: 1,
+                    payer: Addr::unchecked(BIDDER),
+                    amount: Uint128::new(600),
+                    time: second_block.time,
+                    height: second_block.height,
+                }]
+            );
+        }
+
+        #[test]
+        fn try_stats() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            let config = BiddingPeriod {
+                minimum_bid: Uint128::new(100),
+                accepted_bidders: 2,
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            let query_stats = |app: &App| {
+                let res: StatsResponse = app
+                    .wrap()
+                    .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Stats {})
+                    .unwrap();
+                res.stats
+            };
+            let ranked_bid = |bidder: &str, amount: u128| RankedBid {
+                bidder: Addr::unchecked(bidder),
+                amount: Uint128::new(amount),
+            };
+
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 700).unwrap();
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                500,
+            )
+            .unwrap();
+            assert_eq!(
+                query_stats(&app),
+                PeriodStats {
+                    total_deposited: Uint128::new(1200),
+                    bidder_count: 2,
+                    highest_bid: Some(ranked_bid(BIDDER, 700)),
+                    cutoff_bid: Some(ranked_bid(OTHER_BIDDER, 500)),
+                }
+            );
+
+            // Topping up OTHER_BIDDER's bid moves it to the top
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                300,
+            )
+            .unwrap();
+            assert_eq!(
+                query_stats(&app),
+                PeriodStats {
+                    total_deposited: Uint128::new(1500),
+                    bidder_count: 2,
+                    highest_bid: Some(ranked_bid(OTHER_BIDDER, 800)),
+                    cutoff_bid: Some(ranked_bid(BIDDER, 700)),
+                }
+            );
+
+            // Disqualifying OTHER_BIDDER leaves fewer bids than can be accepted
+            let msg = ExecuteMsg::DisqualifyBid {
+                address: Addr::unchecked(OTHER_BIDDER),
+                reason: "Abusive bidder".into(),
+                denylist: false,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(
+                query_stats(&app),
+                PeriodStats {
+                    total_deposited: Uint128::new(700),
+                    bidder_count: 1,
+                    highest_bid: Some(ranked_bid(BIDDER, 700)),
+                    cutoff_bid: None,
+                }
+            );
+
+            // Bidding again fills the accepted bids, so the lowest bid becomes the cut-off
+            bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 200).unwrap();
+            assert_eq!(
+                query_stats(&app).cutoff_bid,
+                Some(ranked_bid(OTHER_BIDDER, 200))
+            );
+
+            // A bid below the cut-off leaves it in place
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                OTHER_BIDDER,
+                Some(THIRD_BIDDER),
+                100,
+            )
+            .unwrap();
+            assert_eq!(
+                query_stats(&app).cutoff_bid,
+                Some(ranked_bid(OTHER_BIDDER, 200))
+            );
+
+            // Raising it above the cut-off moves the cut-off up
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                OTHER_BIDDER,
+                Some(THIRD_BIDDER),
+                400,
+            )
+            .unwrap();
+            assert_eq!(
+                query_stats(&app),
+                PeriodStats {
+                    total_deposited: Uint128::new(1400),
+                    bidder_count: 3,
+                    highest_bid: Some(ranked_bid(BIDDER, 700)),
+                    cutoff_bid: Some(ranked_bid(THIRD_BIDDER, 500)),
+                }
+            );
+
+            // Removing the cut-off bid moves the cut-off down
+            let msg = ExecuteMsg::DisqualifyBid {
+                address: Addr::unchecked(THIRD_BIDDER),
+                reason: "Abusive bidder".into(),
+                denylist: false,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(
+                query_stats(&app),
+                PeriodStats {
+                    total_deposited: Uint128::new(900),
+                    bidder_count: 2,
+                    highest_bid: Some(ranked_bid(BIDDER, 700)),
+                    cutoff_bid: Some(ranked_bid(OTHER_BIDDER, 200)),
+                }
+            );
+        }
+
+        #[test]
+        fn try_simulate_bid() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 800).unwrap();
+
+            let simulate_bid = |app: &App, amount: u128| {
+                let res: SimulateBidResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::SimulateBid {
+                            address: Addr::unchecked(OTHER_BIDDER),
+                            amount: Uint128::new(amount),
+                        },
+                    )
+                    .unwrap();
+                res
+            };
+
+            // A bid of 600 would rank second, and needs 200 more to outrank BIDDER
+            // (ties are ordered by address)
+            assert_eq!(
+                simulate_bid(&app, 600),
+                SimulateBidResponse {
+                    rank: 2,
+                    accepted: false,
+                    top_up_needed: Uint128::new(200),
+                    error: None,
+                }
+            );
+
+            // A bid of 900 would rank first
+            assert_eq!(
+                simulate_bid(&app, 900),
+                SimulateBidResponse {
+                    rank: 1,
+                    accepted: true,
+                    top_up_needed: Uint128::zero(),
+                    error: None,
+                }
+            );
+
+            // A bid below the minimum would be rejected
+            let res = simulate_bid(&app, 100);
+            assert_eq!(
+                res.error,
+                Some(
+                    ContractError::BidBelowMinimum {
+                        minimum: Uint128::new(500)
+                    }
+                    .to_string()
+                )
+            );
+        }
+
+        #[test]
+        fn try_simulate_end_bidding() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            bid(
+                &mut app,
+                &juno_bid_contract,
+                BIDDER,
+                Some(OTHER_BIDDER),
+                500,
+            )
+            .unwrap();
+
+            let simulate_end_bidding = |app: &App, accepted_bids: Vec<&str>| {
+                let res: SimulateEndBiddingResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::SimulateEndBidding {
+                            accepted_bids: accepted_bids.into_iter().map(Addr::unchecked).collect(),
+                            withdrawal_address: Some(Addr::unchecked(ADMIN)),
+                            sender: None,
+                        },
+                    )
+                    .unwrap();
+                res
+            };
+
+            // Accepting BIDDER's bid would withdraw 1000 ujunox and refund OTHER_BIDDER
+            let res = simulate_end_bidding(&app, vec![BIDDER]);
+            assert_eq!(res.error, None);
+            assert_eq!(res.withdrawal_amount, Uint128::new(1000));
+            assert_eq!(res.withdrawal_address, Some(Addr::unchecked(ADMIN)));
+            assert!(!res.held);
+            assert_eq!(
+                res.refunds,
+                vec![Payout {
+                    address: Addr::unchecked(OTHER_BIDDER),
+                    amount: Uint128::new(500),
+                }]
+            );
+
+            // Invalid lists of accepted bids are reported
+            let res = simulate_end_bidding(&app, vec!["juno1unknown"]);
+            assert_eq!(res.error, Some("Unknown Bidder: juno1unknown".into()));
+            let res = simulate_end_bidding(&app, vec![BIDDER, BIDDER]);
+            assert_eq!(
+                res.error,
+                Some("Duplicate Accepted Bid: juno1bidder".into())
+            );
+            let res = simulate_end_bidding(&app, vec![BIDDER, OTHER_BIDDER]);
+            assert_eq!(res.error, Some("Too Many Accepted Bids: 1".into()));
+
+            // Without a withdrawal address, the funds go to the treasury, or to a treasurer
+            // ending the bidding period if there is none
+            let simulate_without_address = |app: &App, sender: Option<&str>| {
+                let res: SimulateEndBiddingResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::SimulateEndBidding {
+                            accepted_bids: vec![Addr::unchecked(BIDDER)],
+                            withdrawal_address: None,
+                            sender: sender.map(Addr::unchecked),
+                        },
+                    )
+                    .unwrap();
+                res
+            };
+            let res = simulate_without_address(&app, Some(ADMIN));
+            assert_eq!(res.withdrawal_address, Some(Addr::unchecked(ADMIN)));
+            let res = simulate_without_address(&app, None);
+            assert_eq!(res.withdrawal_address, None);
+            assert_eq!(res.error, Some("Withdrawal Address Required".into()));
+            let msg = ExecuteMsg::SetTreasury {
+                address: Some(Addr::unchecked("juno1treasury")),
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let res = simulate_without_address(&app, None);
+            assert_eq!(
+                res.withdrawal_address,
+                Some(Addr::unchecked("juno1treasury"))
+            );
+
+            // The bidding period is left untouched
+            let res: StatsResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Stats {})
+                .unwrap();
+            assert_eq!(res.stats.bidder_count, 2);
+        }
+
+        #[test]
+        fn try_end_bidding_validation() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            // Without a bidding period, bids and settlements fail with a typed error
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoBiddingPeriod {}
+            ));
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoBiddingPeriod {}
+            ));
+            let res: SimulateBidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::SimulateBid {
+                        address: Addr::unchecked(BIDDER),
+                        amount: Uint128::new(1000),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                res.error,
+                Some(ContractError::NoBiddingPeriod {}.to_string())
+            );
+
+            let config = BiddingPeriod {
+                accepted_bidders: 2,
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+
+            let end_bidding = |app: &mut App, accepted_bids: Vec<&str>, withdrawal_address| {
+                let msg = ExecuteMsg::EndBidding {
+                    accepted_bids: accepted_bids.into_iter().map(Addr::unchecked).collect(),
+                    withdrawal_address,
+                };
+                let err = app
+                    .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                    .unwrap_err();
+                err.downcast::<ContractError>().unwrap()
+            };
+
+            let err = end_bidding(&mut app, vec![OTHER_BIDDER], None);
+            assert!(
+                matches!(err, ContractError::UnknownBidder { address } if address == OTHER_BIDDER)
+            );
+
+            let err = end_bidding(&mut app, vec![BIDDER, BIDDER], None);
+            assert!(
+                matches!(err, ContractError::DuplicateAcceptedBid { address } if address == BIDDER)
+            );
+
+            let err = end_bidding(&mut app, vec![BIDDER], Some(Addr::unchecked("x")));
+            assert!(matches!(
+                err,
+                ContractError::InvalidWithdrawalAddress { .. }
+            ));
+        }
+
+        #[test]
+        fn try_pause() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+
+            // Only admins can pause the contract
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(ExecuteMsg::Pause {}).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(ExecuteMsg::Pause {}).unwrap(),
+            )
+            .unwrap();
+
+            // Bids and settlement are blocked while paused, which the simulations report
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(err.downcast().unwrap(), ContractError::Paused {}));
+            let res: SimulateBidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::SimulateBid {
+                        address: Addr::unchecked(BIDDER),
+                        amount: Uint128::new(500),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.error, Some(ContractError::Paused {}.to_string()));
+            let res: SimulateEndBiddingResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::SimulateEndBidding {
+                        accepted_bids: vec![Addr::unchecked(BIDDER)],
+                        withdrawal_address: None,
+                        sender: Some(Addr::unchecked(ADMIN)),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.error, Some(ContractError::Paused {}.to_string()));
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(err.downcast().unwrap(), ContractError::Paused {}));
+
+            // Bidders cannot withdraw before the emergency withdrawal delay has passed
+            let emergency_withdraw = juno_bid_contract
+                .call(ExecuteMsg::EmergencyWithdraw { address: None })
+                .unwrap();
+            let err = app
+                .execute(Addr::unchecked(BIDDER), emergency_withdraw.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::EmergencyWithdrawUnavailable { .. }
+            ));
+
+            let res: PauseStatusResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PauseStatus {})
+                .unwrap();
+            assert!(res.paused);
+            app.update_block(|block| block.time = res.emergency_withdraw_at.unwrap());
+
+            // Once the delay has passed, BIDDER recovers their 1000 ujunox
+            app.execute(Addr::unchecked(BIDDER), emergency_withdraw)
+                .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+        }
+
+        #[test]
+        fn try_proceeds_split() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const PLATFORM: &str = "juno1platform";
+            const REFERRER: &str = "juno1referrer";
+            const TREASURY: &str = "juno1treasury";
+            let share = |address: &str, bps: u64| ProceedsShare {
+                address: Addr::unchecked(address),
+                bps,
+            };
+            let balance = |app: &App, address: &str| {
+                app.wrap()
+                    .query_balance(Addr::unchecked(address), NATIVE_DENOM)
+                    .unwrap()
+                    .amount
+            };
+
+            // The shares cannot add up to more than the proceeds, nor repeat an address
+            for split in [
+                vec![share(PLATFORM, 9000), share(REFERRER, 1001)],
+                vec![share(PLATFORM, 500), share(PLATFORM, 500)],
+                vec![share(PLATFORM, 0)],
+            ] {
+                let msg = ExecuteMsg::SetProceedsSplit { split };
+                let err = app
+                    .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                    .unwrap_err();
+                assert!(matches!(
+                    err.downcast().unwrap(),
+                    ContractError::InvalidProceedsSplit {}
+                ));
+            }
+
+            // Only treasurers can split the proceeds
+            let split = vec![share(PLATFORM, 1000), share(REFERRER, 250)];
+            let msg = ExecuteMsg::SetProceedsSplit {
+                split: split.clone(),
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let res: TreasuryResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Treasury {})
+                .unwrap();
+            assert_eq!(res.split, split);
+
+            // Each share is paid its part of the proceeds, and the withdrawal address the rest
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: Some(Addr::unchecked(TREASURY)),
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(balance(&app, PLATFORM), Uint128::new(100));
+            assert_eq!(balance(&app, REFERRER), Uint128::new(25));
+            assert_eq!(balance(&app, TREASURY), Uint128::new(875));
+        }
+
+        #[test]
+        fn try_dispute_window() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const GUARDIAN: &str = "juno1guardian";
+
+            let config = BiddingPeriod {
+                dispute: Some(DisputeWindow {
+                    duration: 3600,
+                    guardian: Addr::unchecked(GUARDIAN),
+                }),
+                ..bidding_period()
+            };
+            let end_bidding = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            let release = juno_bid_contract
+                .call(ExecuteMsg::ReleaseProceeds {})
+                .unwrap();
+            let veto = juno_bid_contract
+                .call(ExecuteMsg::VetoSettlement {})
+                .unwrap();
+
+            // End a bidding period with a dispute window
+            start_bidding(&mut app, &juno_bid_contract, config.clone());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            let res: SimulateEndBiddingResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::SimulateEndBidding {
+                        accepted_bids: vec![Addr::unchecked(BIDDER)],
+                        withdrawal_address: None,
+                        sender: Some(Addr::unchecked(ADMIN)),
+                    },
+                )
+                .unwrap();
+            assert!(res.held);
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(end_bidding.clone()).unwrap(),
+            )
+            .unwrap();
+
+            let res: PendingSettlementResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PendingSettlement {})
+                .unwrap();
+            let settlement = res.settlement.unwrap();
+            assert_eq!(settlement.proceeds, Uint128::new(1000));
+            assert_eq!(settlement.withdrawal_address, Addr::unchecked(ADMIN));
+
+            // No new bidding period can start until the settlement is resolved
+            let msg = ExecuteMsg::StartBidding {
+                config: config.clone(),
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::SettlementPending {}
+            ));
+
+            // The proceeds are held during the dispute window
+            let err = app
+                .execute(Addr::unchecked(BIDDER), release.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::DisputeWindowOpen { .. }
+            ));
+
+            // Only the guardian can veto the result, which refunds the winners
+            let err = app
+                .execute(Addr::unchecked(ADMIN), veto.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(Addr::unchecked(GUARDIAN), veto.clone())
+                .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+
+            // Run a second bidding period, which is not vetoed
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(end_bidding).unwrap(),
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+            // Once the window has closed the result can no longer be vetoed,
+            // and anyone can release the proceeds
+            let err = app.execute(Addr::unchecked(GUARDIAN), veto).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::DisputeWindowClosed {}
+            ));
+            app.execute(Addr::unchecked(BIDDER), release.clone())
+                .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(ADMIN), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1000));
+
+            let err = app.execute(Addr::unchecked(BIDDER), release).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoPendingSettlement {}
+            ));
+        }
+
+        #[test]
+        fn try_proposals() {
+            const OTHER_ADMIN: &str = "juno1otheradmin";
+            const THIRD_ADMIN: &str = "juno1thirdadmin";
+
+            let mut app = mock_app();
+            let juno_bid_id = app.store_code(contract());
+            let admins = vec![
+                Addr::unchecked(ADMIN),
+                Addr::unchecked(OTHER_ADMIN),
+                Addr::unchecked(THIRD_ADMIN),
+            ];
+
+            // The threshold cannot exceed the number of admins
+            let msg = InstantiateMsg {
+                admins: admins.clone(),
+                threshold: Some(4),
+                emergency_withdraw_delay: None,
+            };
+            let err = app
+                .instantiate_contract(juno_bid_id, Addr::unchecked(ADMIN), &msg, &[], "test", None)
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::InvalidThreshold {}
+            ));
+
+            // Admins cannot be listed twice to reach the threshold
+            let duplicated = InstantiateMsg {
+                admins: vec![Addr::unchecked(ADMIN), Addr::unchecked(ADMIN)],
+                threshold: Some(2),
+                emergency_withdraw_delay: None,
+            };
+            let err = app
+                .instantiate_contract(
+                    juno_bid_id,
+                    Addr::unchecked(ADMIN),
+                    &duplicated,
+                    &[],
+                    "test",
+                    None,
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::DuplicateAdmin { .. }
+            ));
+
+            let msg = InstantiateMsg {
+                threshold: Some(2),
+                ..msg
+            };
+            let juno_bid_contract = JunoBidContract(
+                app.instantiate_contract(
+                    juno_bid_id,
+                    Addr::unchecked(ADMIN),
+                    &msg,
+                    &[],
+                    "test",
+                    None,
+                )
+                .unwrap(),
+            );
+
+            // Privileged actions can no longer be executed by a single admin
+            let msg = ExecuteMsg::StartBidding {
+                config: bidding_period(),
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ProposalRequired {}
+            ));
+
+            // ADMIN proposes to start a bidding period, which is not executed yet
+            let expires = Expiration::AtTime(mock_env().block.time.plus_seconds(3600));
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::StartBidding {
+                    config: bidding_period(),
+                },
+                expires,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, None);
+
+            // Admins cannot approve twice, and non-admins cannot approve
+            let approve = juno_bid_contract
+                .call(ExecuteMsg::Approve { proposal_id: 1 })
+                .unwrap();
+            let err = app
+                .execute(Addr::unchecked(ADMIN), approve.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::AlreadyApproved {}
+            ));
+            let err = app
+                .execute(Addr::unchecked(BIDDER), approve.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+
+            let err = app
+                .execute(
+                    Addr::unchecked(OTHER_ADMIN),
+                    juno_bid_contract
+                        .call(ExecuteMsg::Approve { proposal_id: 9 })
+                        .unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ProposalNotFound { proposal_id: 9 }
+            ));
+            let pending_proposals = |app: &App| -> Vec<u64> {
+                let res: ProposalsResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::Proposals {
+                            pending: Some(true),
+                            start_after: None,
+                            limit: None,
+                        },
+                    )
+                    .unwrap();
+                res.proposals.iter().map(|p| p.id).collect()
+            };
+            assert_eq!(pending_proposals(&app), vec![1]);
+
+            // The second approval executes the proposal
+            app.execute(Addr::unchecked(OTHER_ADMIN), approve.clone())
+                .unwrap();
+            assert_eq!(pending_proposals(&app), Vec::<u64>::new());
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, Some(bidding_period()));
+            let err = app
+                .execute(Addr::unchecked(THIRD_ADMIN), approve)
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ProposalExecuted {}
+            ));
+
+            // Proposals cannot be approved once expired
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::EndBidding {
+                    accepted_bids: vec![Addr::unchecked(BIDDER)],
+                    withdrawal_address: None,
+                },
+                expires,
+            };
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(msg.clone()).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(pending_proposals(&app), vec![2]);
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+            assert_eq!(pending_proposals(&app), Vec::<u64>::new());
+            let err = app
+                .execute(
+                    Addr::unchecked(OTHER_ADMIN),
+                    juno_bid_contract
+                        .call(ExecuteMsg::Approve { proposal_id: 2 })
+                        .unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ProposalExpired {}
+            ));
+
+            // The proceeds of an approved EndBidding proposal go to the proposer
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::EndBidding {
+                    accepted_bids: vec![Addr::unchecked(BIDDER)],
+                    withdrawal_address: None,
+                },
+                expires: Expiration::Never {},
+            };
+            app.execute(
+                Addr::unchecked(THIRD_ADMIN),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract
+                    .call(ExecuteMsg::Approve { proposal_id: 3 })
+                    .unwrap(),
+            )
+            .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(THIRD_ADMIN), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1000));
+
+            // Lowering the threshold also goes through a proposal
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::UpdateAdmins {
+                    admins,
+                    threshold: 1,
+                },
+                expires: Expiration::Never {},
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            app.execute(
+                Addr::unchecked(THIRD_ADMIN),
+                juno_bid_contract
+                    .call(ExecuteMsg::Approve { proposal_id: 4 })
+                    .unwrap(),
+            )
+            .unwrap();
+            let config = BiddingPeriod {
+                expires_at: Expiration::AtTime(mock_env().block.time.plus_seconds(7200)),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+
+            let res: ProposalsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Proposals {
+                        pending: None,
+                        start_after: Some(1),
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            let executed: Vec<_> = res.proposals.iter().map(|p| p.executed).collect();
+            assert_eq!(executed, vec![false, true, true]);
+            let res: ProposalResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Proposal { proposal_id: 3 },
+                )
+                .unwrap();
+            assert_eq!(
+                res.proposal.approvals,
+                vec![Addr::unchecked(THIRD_ADMIN), Addr::unchecked(ADMIN)]
+            );
+        }
+
+        #[test]
+        fn try_proposals_by_role_holders() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const OTHER_ADMIN: &str = "juno1otheradmin";
+            const OPERATOR: &str = "juno1operator";
+            const OTHER_OPERATOR: &str = "juno1otheroperator";
+
+            for address in [OPERATOR, OTHER_OPERATOR] {
+                let msg = ExecuteMsg::GrantRole {
+                    address: Addr::unchecked(address),
+                    role: Role::Operator,
+                };
+                app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                    .unwrap();
+            }
+            app.wasm_sudo(
+                juno_bid_contract.addr(),
+                &SudoMsg::UpdateAdmins {
+                    admins: vec![Addr::unchecked(ADMIN), Addr::unchecked(OTHER_ADMIN)],
+                    threshold: Some(2),
+                },
+            )
+            .unwrap();
+
+            // Operators can propose and approve, but only the approvals of admins count
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::StartBidding {
+                    config: bidding_period(),
+                },
+                expires: Expiration::Never {},
+            };
+            app.execute(
+                Addr::unchecked(OPERATOR),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            let approve = juno_bid_contract
+                .call(ExecuteMsg::Approve { proposal_id: 1 })
+                .unwrap();
+            app.execute(Addr::unchecked(OTHER_OPERATOR), approve.clone())
+                .unwrap();
+            app.execute(Addr::unchecked(ADMIN), approve.clone())
+                .unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, None);
+
+            app.execute(Addr::unchecked(OTHER_ADMIN), approve).unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, Some(bidding_period()));
+        }
+
+        #[test]
+        fn try_roles() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const OPERATOR: &str = "juno1operator";
+            const SETTLER: &str = "juno1settler";
+            const TREASURER: &str = "juno1treasurer";
+            const TREASURY: &str = "juno1treasury";
+
+            for (address, role) in [
+                (OPERATOR, Role::Operator),
+                (SETTLER, Role::Settler),
+                (TREASURER, Role::Treasurer),
+            ] {
+                let msg = ExecuteMsg::GrantRole {
+                    address: Addr::unchecked(address),
+                    role,
+                };
+                app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                    .unwrap();
+            }
+
+            // The owner role is held by the admins only
+            let msg = ExecuteMsg::GrantRole {
+                address: Addr::unchecked(OPERATOR),
+                role: Role::Owner,
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::OwnerNotGrantable {}
+            ));
+
+            // Only operators can start and cancel bidding periods
+            let msg = ExecuteMsg::StartBidding {
+                config: bidding_period(),
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(SETTLER),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(OPERATOR),
+                juno_bid_contract.call(msg.clone()).unwrap(),
+            )
+            .unwrap();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            app.execute(
+                Addr::unchecked(OPERATOR),
+                juno_bid_contract
+                    .call(ExecuteMsg::CancelBidding {})
+                    .unwrap(),
+            )
+            .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+
+            app.execute(
+                Addr::unchecked(OPERATOR),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+
+            // Settlers cannot choose where the funds are withdrawn to
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: Some(Addr::unchecked(SETTLER)),
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(SETTLER),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+
+            // Without a treasury, settlers cannot have the funds withdrawn to themselves
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(SETTLER),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::WithdrawalAddressRequired {}
+            ));
+
+            // Once the treasurer has set a treasury, the funds are withdrawn to it
+            let msg = ExecuteMsg::SetTreasury {
+                address: Some(Addr::unchecked(TREASURY)),
+            };
+            app.execute(
+                Addr::unchecked(TREASURER),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            app.execute(
+                Addr::unchecked(SETTLER),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(TREASURY), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1000));
+
+            // Admins hold every role
+            let roles = |app: &App, address: &str| -> Vec<Role> {
+                let res: RolesResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::Roles {
+                            address: Addr::unchecked(address),
+                        },
+                    )
+                    .unwrap();
+                res.roles
+            };
+            assert_eq!(roles(&app, ADMIN).len(), 4);
+            assert_eq!(roles(&app, SETTLER), vec![Role::Settler]);
+
+            let msg = ExecuteMsg::RevokeRole {
+                address: Addr::unchecked(SETTLER),
+                role: Role::Settler,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(roles(&app, SETTLER), vec![]);
+        }
+
+        #[test]
+        fn try_admin_transfer() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const NEW_ADMIN: &str = "juno1newadmin";
+
+            let expiry = Expiration::AtTime(mock_env().block.time.plus_seconds(3600));
+            let msg = ExecuteMsg::ProposeAdminTransfer {
+                new_admin: Addr::unchecked(NEW_ADMIN),
+                expiry,
+            };
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(msg.clone()).unwrap(),
+            )
+            .unwrap();
+
+            let res: PendingAdminTransferResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PendingAdminTransfer {})
+                .unwrap();
+            assert_eq!(
+                res.transfer,
+                Some(AdminTransfer {
+                    from: Addr::unchecked(ADMIN),
+                    to: Addr::unchecked(NEW_ADMIN),
+                    expiry,
+                })
+            );
+
+            // Only the nominee can accept the transfer, and the admin can cancel it
+            let accept = juno_bid_contract
+                .call(ExecuteMsg::AcceptAdminTransfer {})
+                .unwrap();
+            let err = app
+                .execute(Addr::unchecked(BIDDER), accept.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract
+                    .call(ExecuteMsg::CancelAdminTransfer {})
+                    .unwrap(),
+            )
+            .unwrap();
+            let err = app
+                .execute(Addr::unchecked(NEW_ADMIN), accept.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoPendingAdminTransfer {}
+            ));
+
+            // The transfer cannot be accepted once expired
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(msg.clone()).unwrap(),
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+            let err = app
+                .execute(Addr::unchecked(NEW_ADMIN), accept.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::AdminTransferExpired {}
+            ));
+
+            // Once accepted, the nominee replaces the admin
+            let msg = ExecuteMsg::ProposeAdminTransfer {
+                new_admin: Addr::unchecked(NEW_ADMIN),
+                expiry: Expiration::Never {},
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            app.execute(Addr::unchecked(NEW_ADMIN), accept).unwrap();
+
+            let config = BiddingPeriod {
+                expires_at: Expiration::AtTime(mock_env().block.time.plus_seconds(7200)),
+                ..bidding_period()
+            };
+            let msg = ExecuteMsg::StartBidding { config };
+            let err = app
+                .execute(
+                    Addr::unchecked(ADMIN),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(NEW_ADMIN),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn try_admin_transfer_after_admin_update() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const NEW_ADMIN: &str = "juno1newadmin";
+
+            let msg = ExecuteMsg::ProposeAdminTransfer {
+                new_admin: Addr::unchecked(NEW_ADMIN),
+                expiry: Expiration::Never {},
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            // Making the nominee an admin through a proposal cancels the transfer
+            let msg = ExecuteMsg::Propose {
+                action: ProposalAction::UpdateAdmins {
+                    admins: vec![Addr::unchecked(ADMIN), Addr::unchecked(NEW_ADMIN)],
+                    threshold: 1,
+                },
+                expires: Expiration::Never {},
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            let res: PendingAdminTransferResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PendingAdminTransfer {})
+                .unwrap();
+            assert_eq!(res.transfer, None);
+            let err = app
+                .execute(
+                    Addr::unchecked(NEW_ADMIN),
+                    juno_bid_contract
+                        .call(ExecuteMsg::AcceptAdminTransfer {})
+                        .unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoPendingAdminTransfer {}
+            ));
+
+            // Both admins still hold their seats
+            let res: RolesResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Roles {
+                        address: Addr::unchecked(ADMIN),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.roles.len(), 4);
+        }
+
+        #[test]
+        fn try_sudo() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const NEW_ADMIN: &str = "juno1newadmin";
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+
+            // Governance can pause the contract
+            app.wasm_sudo(juno_bid_contract.addr(), &SudoMsg::Pause {})
+                .unwrap();
+            let res: PauseStatusResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PauseStatus {})
+                .unwrap();
+            assert!(res.paused);
+            app.wasm_sudo(juno_bid_contract.addr(), &SudoMsg::Unpause {})
+                .unwrap();
+
+            // Governance can cancel the bidding period, refunding all bids
+            app.wasm_sudo(juno_bid_contract.addr(), &SudoMsg::ForceCancel {})
+                .unwrap();
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, None);
+
+            // Governance can replace the admins
+            let msg = SudoMsg::UpdateAdmins {
+                admins: vec![Addr::unchecked(NEW_ADMIN)],
+                threshold: None,
+            };
+            app.wasm_sudo(juno_bid_contract.addr(), &msg).unwrap();
+            let msg = ExecuteMsg::StartBidding {
+                config: bidding_period(),
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(ADMIN),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(NEW_ADMIN),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+        }
+
+        #[test]
+        fn try_hooks() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let hook_id = app.store_code(contract_hook());
+            let hook = app
+                .instantiate_contract(hook_id, Addr::unchecked(ADMIN), &false, &[], "hook", None)
+                .unwrap();
+            let failing_hook = app
+                .instantiate_contract(hook_id, Addr::unchecked(ADMIN), &true, &[], "hook", None)
+                .unwrap();
+            let add_hook = |app: &mut App, address: &Addr, on_error: HookFailure| {
+                let msg = ExecuteMsg::AddHook {
+                    address: address.clone(),
+                    on_error,
+                    gas_limit: None,
+                };
+                app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+            };
+            let notifications = |app: &App| -> u64 {
+                app.wrap()
+                    .query_wasm_smart(hook.clone(), &Empty {})
+                    .unwrap()
+            };
+
+            // Only admins can register hooks
+            let msg = ExecuteMsg::AddHook {
+                address: hook.clone(),
+                on_error: HookFailure::Revert,
+                gas_limit: None,
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            add_hook(&mut app, &hook, HookFailure::Revert).unwrap();
+
+            // Bids and settlements are notified
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            assert_eq!(notifications(&app), 1);
+
+            // A failing hook reverts the bid unless its failures are ignored
+            add_hook(&mut app, &failing_hook, HookFailure::Revert).unwrap();
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(err.root_cause().to_string().contains("hook failed"));
+            let msg = ExecuteMsg::RemoveHook {
+                address: failing_hook.clone(),
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            add_hook(&mut app, &failing_hook, HookFailure::Ignore).unwrap();
+            let res = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            assert!(res.has_event(&Event::new("wasm").add_attribute("method", "hook_failed")));
+            assert_eq!(notifications(&app), 2);
+
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(notifications(&app), 3);
+
+            // Refunds of cancelled bidding periods are notified
+            let config = BiddingPeriod {
+                expires_at: Expiration::AtTime(app.block_info().time.plus_seconds(1440)),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            let msg = ExecuteMsg::CancelBidding {};
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            assert_eq!(notifications(&app), 5);
+
+            let res: HooksResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Hooks {})
+                .unwrap();
+            assert_eq!(res.hooks.len(), 2);
+        }
+
+        #[test]
+        fn try_hooks_on_refunds() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let hook_id = app.store_code(contract_hook());
+            let failing_hook = app
+                .instantiate_contract(hook_id, Addr::unchecked(ADMIN), &true, &[], "hook", None)
+                .unwrap();
+            let hook_failed = Event::new("wasm").add_attribute("method", "hook_failed");
+
+            // A failing hook registered to revert cannot block a cancellation by governance
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            let msg = ExecuteMsg::AddHook {
+                address: failing_hook.clone(),
+                on_error: HookFailure::Revert,
+                gas_limit: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            let res = app
+                .wasm_sudo(juno_bid_contract.addr(), &SudoMsg::ForceCancel {})
+                .unwrap();
+            assert!(res.has_event(&hook_failed));
+
+            // Nor an emergency withdrawal
+            let config = BiddingPeriod {
+                expires_at: Expiration::AtTime(app.block_info().time.plus_seconds(1440)),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            let msg = ExecuteMsg::RemoveHook {
+                address: failing_hook.clone(),
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            let msg = ExecuteMsg::AddHook {
+                address: failing_hook,
+                on_error: HookFailure::Revert,
+                gas_limit: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(ExecuteMsg::Pause {}).unwrap(),
+            )
+            .unwrap();
+            let res: PauseStatusResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::PauseStatus {})
+                .unwrap();
+            app.update_block(|block| block.time = res.emergency_withdraw_at.unwrap());
+            let res = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract
+                        .call(ExecuteMsg::EmergencyWithdraw { address: None })
+                        .unwrap(),
+                )
+                .unwrap();
+            assert!(res.has_event(&hook_failed));
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+        }
+
+        #[test]
+        fn try_receipts() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let cw721_id = app.store_code(contract_cw721());
+            let collection = app
+                .instantiate_contract(
+                    cw721_id,
+                    Addr::unchecked(ADMIN),
+                    &Empty {},
+                    &[],
+                    "receipts",
+                    None,
+                )
+                .unwrap();
+
+            // Give OTHER_BIDDER 500 ujunox
+            app.send_tokens(
+                Addr::unchecked(BIDDER),
+                Addr::unchecked(OTHER_BIDDER),
+                &[coin(500, NATIVE_DENOM)],
+            )
+            .unwrap();
+
+            let config = BiddingPeriod {
+                accepted_bidders: 2,
+                receipt_collection: Some(collection.clone()),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 500).unwrap();
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(OTHER_BIDDER), Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            // Receipts are ranked by amount
+            let res: ReceiptResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Receipt {
+                        period_id: 1,
+                        address: Addr::unchecked(OTHER_BIDDER),
+                    },
+                )
+                .unwrap();
+            let receipt = res.receipt.unwrap();
+            assert_eq!(receipt.rank, 2);
+            assert_eq!(receipt.amount, Uint128::new(500));
+            assert_eq!(receipt.period_name, "My Bidding Period");
+            assert!(!receipt.claimed);
+
+            // Winners mint their own receipt, once
+            let claim = juno_bid_contract
+                .call(ExecuteMsg::ClaimReceipt { period_id: 1 })
+                .unwrap();
+            app.execute(Addr::unchecked(BIDDER), claim.clone()).unwrap();
+            let tokens: Vec<(String, String)> =
+                app.wrap().query_wasm_smart(collection, &Empty {}).unwrap();
+            assert_eq!(tokens, vec![("1-1".to_string(), BIDDER.to_string())]);
+
+            let err = app
+                .execute(Addr::unchecked(BIDDER), claim.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ReceiptAlreadyClaimed { period_id: 1 }
+            ));
+            let err = app.execute(Addr::unchecked(ADMIN), claim).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoReceipt { period_id: 1 }
+            ));
+        }
+
+        #[test]
+        fn try_schedule() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            let msg = ExecuteMsg::CreateSchedule {
+                template: bidding_period(),
+                interval: Duration::Time(3600),
+                count: 2,
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            // Anyone can start the next scheduled bidding period
+            let start = juno_bid_contract
+                .call(ExecuteMsg::StartScheduledBidding {})
+                .unwrap();
+            app.execute(Addr::unchecked(BIDDER), start.clone()).unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            let period = res.bidding_period.unwrap();
+            assert_eq!(period.name, "My Bidding Period #1");
+            assert_eq!(
+                period.expires_at,
+                Expiration::AtTime(app.block_info().time.plus_seconds(3600))
+            );
+
+            // ...once the previous one has ended
+            let err = app
+                .execute(Addr::unchecked(BIDDER), start.clone())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BiddingPeriodActive {}
+            ));
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            let msg = ExecuteMsg::EndBidding {
+                accepted_bids: vec![Addr::unchecked(BIDDER)],
+                withdrawal_address: None,
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            app.update_block(|block| block.time = block.time.plus_seconds(7200));
+            app.execute(Addr::unchecked(BIDDER), start.clone()).unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            let period = res.bidding_period.unwrap();
+            assert_eq!(period.name, "My Bidding Period #2");
+            assert_eq!(
+                period.expires_at,
+                Expiration::AtTime(app.block_info().time.plus_seconds(3600))
+            );
+
+            // The schedule is removed once all its bidding periods have started
+            let res: ScheduleResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Schedule {})
+                .unwrap();
+            assert_eq!(res.schedule, None);
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract
+                    .call(ExecuteMsg::CancelBidding {})
+                    .unwrap(),
+            )
+            .unwrap();
+            let err = app.execute(Addr::unchecked(BIDDER), start).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::NoSchedule {}
+            ));
+        }
+
+        #[test]
+        fn try_height_expiry() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let height = app.block_info().height;
+
+            // A bidding period must expire, after it starts
+            let start_bidding_msg = |config: BiddingPeriod| {
+                juno_bid_contract
+                    .call(ExecuteMsg::StartBidding { config })
+                    .unwrap()
+            };
+            for config in [
+                BiddingPeriod {
+                    expires_at: Expiration::Never {},
+                    ..bidding_period()
+                },
+                BiddingPeriod {
+                    starts_at: Some(Scheduled::AtHeight(height + 20)),
+                    expires_at: Expiration::AtHeight(height + 10),
+                    ..bidding_period()
+                },
+            ] {
+                let err = app
+                    .execute(Addr::unchecked(ADMIN), start_bidding_msg(config))
+                    .unwrap_err();
+                assert!(matches!(
+                    err.downcast().unwrap(),
+                    ContractError::InvalidExpiry {}
+                ));
+            }
+
+            let config = BiddingPeriod {
+                starts_at: Some(Scheduled::AtHeight(height + 5)),
+                expires_at: Expiration::AtHeight(height + 10),
+                ..bidding_period()
+            };
+            app.execute(Addr::unchecked(ADMIN), start_bidding_msg(config))
+                .unwrap();
+
+            // Bids are only accepted between the start and expiry heights
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BiddingPeriodNotStarted { .. }
+            ));
+            app.update_block(|block| block.height += 5);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap();
+            app.update_block(|block| block.height += 5);
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BiddingPeriodExpired {}
+            ));
+        }
+
+        #[test]
+        fn try_update_bidding_period() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 600).unwrap();
+
+            let update = |app: &mut App, config: BiddingPeriod| {
+                let msg = ExecuteMsg::UpdateBiddingPeriod { config };
+                app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+            };
+
+            // Unsafe changes are rejected
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    denom: "uatom".into(),
+                    ..bidding_period()
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ImmutableField { field } if field == "denom"
+            ));
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    expires_at: Expiration::AtTime(mock_env().block.time.plus_seconds(60)),
+                    ..bidding_period()
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ExpiryShortened {}
+            ));
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    expires_at: Expiration::Never {},
+                    ..bidding_period()
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::InvalidExpiry {}
+            ));
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    minimum_bid: Uint128::new(700),
+                    ..bidding_period()
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::MinimumAboveBids { .. }
+            ));
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    accepted_bidders: 0,
+                    ..bidding_period()
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::AcceptedBiddersLowered {}
+            ));
+
+            // Safe changes are applied
+            let config = BiddingPeriod {
+                name: "Renamed Bidding Period".into(),
+                description: Some("Fixed a typo".into()),
+                expires_at: Expiration::AtTime(mock_env().block.time.plus_seconds(7200)),
+                minimum_bid: Uint128::new(600),
+                accepted_bidders: 2,
+                ..bidding_period()
+            };
+            update(&mut app, config.clone()).unwrap();
+            let res: BiddingPeriodResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::BiddingPeriod {})
+                .unwrap();
+            assert_eq!(res.bidding_period, Some(config.clone()));
+
+            // The cut-off bid follows the number of accepted bids
+            let res: StatsResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Stats {})
+                .unwrap();
+            assert_eq!(res.stats.cutoff_bid, None);
+
+            // An expired bidding period cannot be reopened
+            app.update_block(|block| block.time = block.time.plus_seconds(7200));
+            let err = update(
+                &mut app,
+                BiddingPeriod {
+                    expires_at: Expiration::AtTime(mock_env().block.time.plus_seconds(14400)),
+                    ..config
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BiddingPeriodExpired {}
+            ));
+        }
+
+        #[test]
+        fn try_metadata() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            let terms_hash = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+            // Metadata beyond the length limits is rejected
+            let metadata = PeriodMetadata {
+                image: Some(
+                    "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".into(),
+                ),
+                links: vec!["https://example.com".into()],
+                category: Some("art".into()),
+                tags: vec!["x".repeat(33)],
+                terms_hash: Some(terms_hash.into()),
+            };
+            let msg = ExecuteMsg::StartBidding {
+                config: BiddingPeriod {
+                    metadata: Some(metadata.clone()),
+                    ..bidding_period()
+                },
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::MetadataTooLong { field, max: 32 } if field == "tag"
+            ));
+            let msg = ExecuteMsg::StartBidding {
+                config: BiddingPeriod {
+                    metadata: Some(PeriodMetadata {
+                        tags: vec![],
+                        terms_hash: Some(terms_hash.to_uppercase()),
+                        ..metadata.clone()
+                    }),
+                    ..bidding_period()
+                },
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::InvalidTermsHash {}
+            ));
+
+            // Limits are in characters, so non-ASCII text can use all of them
+            let metadata = PeriodMetadata {
+                category: Some("é".repeat(32)),
+                tags: vec!["collectible".into()],
+                ..metadata
+            };
+            let config = BiddingPeriod {
+                metadata: Some(metadata.clone()),
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config.clone());
+
+            // Bids must acknowledge the terms of sale
+            let err = bid(&mut app, &juno_bid_contract, BIDDER, None, 500).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::TermsNotAccepted { .. }
+            ));
+            let msg = ExecuteMsg::Bid {
+                address: None,
+                attestation: None,
+                terms_hash: Some(terms_hash.into()),
+            };
+            execute_with_funds(&mut app, &juno_bid_contract, BIDDER, msg, 500).unwrap();
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(BIDDER),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.bid.unwrap().terms_hash, Some(terms_hash.to_string()));
+
+            // The terms cannot change once bidding started, but the presentation can
+            let msg = ExecuteMsg::UpdateBiddingPeriod {
+                config: BiddingPeriod {
+                    metadata: Some(PeriodMetadata {
+                        terms_hash: None,
+                        ..metadata.clone()
+                    }),
+                    ..config.clone()
+                },
+            };
+            let err = app
+                .execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::ImmutableField { field } if field == "metadata.terms_hash"
+            ));
+            let msg = ExecuteMsg::UpdateBiddingPeriod {
+                config: BiddingPeriod {
+                    metadata: Some(PeriodMetadata {
+                        category: Some("music".into()),
+                        ..metadata
+                    }),
+                    ..config
+                },
+            };
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+        }
+
+        #[test]
+        fn try_transfer_bid() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const NEW_WALLET: &str = "juno1newwallet";
+
+            // Give OTHER_BIDDER 500 ujunox
+            app.send_tokens(
+                Addr::unchecked(BIDDER),
+                Addr::unchecked(OTHER_BIDDER),
+                &[coin(500, NATIVE_DENOM)],
+            )
+            .unwrap();
+
+            let transfer = |app: &mut App, sender: &str, to: &str| {
+                let msg = ExecuteMsg::TransferBid {
+                    from: None,
+                    to: Addr::unchecked(to),
+                };
+                app.execute(
+                    Addr::unchecked(sender),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+            };
+            let query_bid = |app: &App, address: &str| {
+                let res: BidResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::Bid {
+                            address: Addr::unchecked(address),
+                        },
+                    )
+                    .unwrap();
+                res.bid
+            };
+
+            // Bids cannot be transferred unless the bidding period allows it
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 600).unwrap();
+            let err = transfer(&mut app, BIDDER, NEW_WALLET).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BidTransfersDisabled {}
+            ));
+            let msg = ExecuteMsg::CancelBidding {};
+            app.execute(Addr::unchecked(ADMIN), juno_bid_contract.call(msg).unwrap())
+                .unwrap();
+
+            let config = BiddingPeriod {
+                transferable_bids: true,
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 600).unwrap();
+            bid(&mut app, &juno_bid_contract, OTHER_BIDDER, None, 500).unwrap();
+
+            // The bid moves to the new address
+            transfer(&mut app, BIDDER, NEW_WALLET).unwrap();
+            assert_eq!(query_bid(&app, BIDDER), None);
+            let moved = query_bid(&app, NEW_WALLET).unwrap();
+            assert_eq!(moved.bidder, Addr::unchecked(NEW_WALLET));
+            assert_eq!(moved.amount, Uint128::new(600));
+            assert_eq!(
+                moved.payers,
+                vec![PayerShare {
+                    payer: Addr::unchecked(BIDDER),
+                    amount: Uint128::new(600),
+                }]
+            );
+
+            // Transferring to an existing bidder merges the bids
+            transfer(&mut app, OTHER_BIDDER, NEW_WALLET).unwrap();
+            let merged = query_bid(&app, NEW_WALLET).unwrap();
+            assert_eq!(merged.amount, Uint128::new(1100));
+            assert_eq!(merged.payers.len(), 2);
+            let res: StatsResponse = app
+                .wrap()
+                .query_wasm_smart(juno_bid_contract.addr(), &QueryMsg::Stats {})
+                .unwrap();
+            assert_eq!(res.stats.bidder_count, 1);
+            assert_eq!(res.stats.total_deposited, Uint128::new(1100));
+
+            let err = transfer(&mut app, NEW_WALLET, NEW_WALLET).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::CannotTransferToSelf {}
+            ));
+
+            // Bids cannot be transferred once the bidding period has expired
+            app.update_block(|block| block.time = block.time.plus_seconds(1440));
+            let err = transfer(&mut app, NEW_WALLET, BIDDER).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::BiddingPeriodExpired {}
+            ));
+        }
+
+        #[test]
+        fn try_operators() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+            const OPERATOR: &str = "juno1operator";
+            const NEW_WALLET: &str = "juno1newwallet";
+
+            let config = BiddingPeriod {
+                transferable_bids: true,
+                ..bidding_period()
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 600).unwrap();
+
+            let transfer = |app: &mut App, to: &str| {
+                let msg = ExecuteMsg::TransferBid {
+                    from: Some(Addr::unchecked(BIDDER)),
+                    to: Addr::unchecked(to),
+                };
+                app.execute(
+                    Addr::unchecked(OPERATOR),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+            };
+            let query_operators = |app: &App, include_expired: bool| {
+                let res: OperatorsResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        juno_bid_contract.addr(),
+                        &QueryMsg::Operators {
+                            owner: Addr::unchecked(BIDDER),
+                            include_expired: Some(include_expired),
+                            start_after: None,
+                            limit: None,
+                        },
+                    )
+                    .unwrap();
+                res.operators
+            };
+
+            // Only approved operators can manage a bid
+            let err = transfer(&mut app, NEW_WALLET).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+
+            let expires = Expiration::AtTime(mock_env().block.time.plus_seconds(600));
+            let msg = ExecuteMsg::ApproveOperator {
+                operator: Addr::unchecked(OPERATOR),
+                expires: Some(expires),
+            };
+            app.execute(
+                Addr::unchecked(BIDDER),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                query_operators(&app, false),
+                vec![OperatorInfo {
+                    operator: Addr::unchecked(OPERATOR),
+                    expires,
+                }]
+            );
+
+            // The operator moves the bid out of BIDDER's wallet and back
+            transfer(&mut app, NEW_WALLET).unwrap();
+            let res: BidResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    juno_bid_contract.addr(),
+                    &QueryMsg::Bid {
+                        address: Addr::unchecked(NEW_WALLET),
+                    },
+                )
+                .unwrap();
+            assert_eq!(res.bid.unwrap().amount, Uint128::new(600));
+            let msg = ExecuteMsg::TransferBid {
+                from: Some(Addr::unchecked(NEW_WALLET)),
+                to: Addr::unchecked(BIDDER),
+            };
+            let err = app
+                .execute(
+                    Addr::unchecked(OPERATOR),
+                    juno_bid_contract.call(msg.clone()).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            app.execute(
+                Addr::unchecked(NEW_WALLET),
+                juno_bid_contract.call(msg).unwrap(),
+            )
+            .unwrap();
+
+            // Expired approvals no longer grant access
+            app.update_block(|block| block.time = block.time.plus_seconds(600));
+            let err = transfer(&mut app, NEW_WALLET).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::Unauthorized {}
+            ));
+            assert_eq!(query_operators(&app, false), vec![]);
+            assert_eq!(query_operators(&app, true).len(), 1);
+
+            // Approvals can be revoked
+            let msg = ExecuteMsg::RevokeOperator {
+                operator: Addr::unchecked(OPERATOR),
+            };
+            app.execute(
+                Addr::unchecked(BIDDER),
+                juno_bid_contract.call(msg.clone()).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(query_operators(&app, true), vec![]);
+            let err = app
+                .execute(
+                    Addr::unchecked(BIDDER),
+                    juno_bid_contract.call(msg).unwrap(),
+                )
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::OperatorNotApproved { .. }
+            ));
+        }
+
+        #[test]
+        fn try_settle_without_winners() {
+            let (mut app, juno_bid_contract) = proper_instantiate();
+
+            let config = BiddingPeriod {
+                dispute: Some(DisputeWindow {
+                    duration: 3600,
+                    guardian: Addr::unchecked("juno1guardian"),
+                }),
+                ..bidding_period()
+            };
+            let end_bidding = ExecuteMsg::EndBidding {
+                accepted_bids: vec![],
+                withdrawal_address: None,
+            };
+
+            // Without a dispute window, the losing bids are refunded and nothing is withdrawn
+            start_bidding(&mut app, &juno_bid_contract, bidding_period());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(end_bidding.clone()).unwrap(),
+            )
+            .unwrap();
+
+            // With a dispute window, the empty settlement can be released once it closes
+            start_bidding(&mut app, &juno_bid_contract, config.clone());
+            bid(&mut app, &juno_bid_contract, BIDDER, None, 1000).unwrap();
+            app.execute(
+                Addr::unchecked(ADMIN),
+                juno_bid_contract.call(end_bidding).unwrap(),
+            )
+            .unwrap();
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+            app.execute(
+                Addr::unchecked(BIDDER),
+                juno_bid_contract
+                    .call(ExecuteMsg::ReleaseProceeds {})
+                    .unwrap(),
+            )
+            .unwrap();
+
+            let balance = app
+                .wrap()
+                .query_balance(Addr::unchecked(BIDDER), NATIVE_DENOM)
+                .unwrap()
+                .amount;
+            assert_eq!(balance, Uint128::new(1500));
+
+            // A new bidding period can then start
+            let config = BiddingPeriod {
+                expires_at: Expiration::AtTime(app.block_info().time.plus_seconds(1440)),
+                ..config
+            };
+            start_bidding(&mut app, &juno_bid_contract, config);
+        }
     }
 }
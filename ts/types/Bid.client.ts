@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Addr, InstantiateMsg, ExecuteMsg, Timestamp, Uint64, Uint128, BiddingPeriod, QueryMsg, BidResponse, Bid, BiddingPeriodResponse, BidsResponse } from "./Bid.types";
+import { Addr, InstantiateMsg, ExecuteMsg, Attestor, Binary, Expiration, Timestamp, Uint64, Uint128, RefundTarget, Scheduled, Duration, Role, ProposalAction, HookFailure, BiddingPeriod, DisputeWindow, GroupRestriction, PeriodMetadata, ProceedsShare, Attestation, QueryMsg, MigrateMsg, SudoMsg, BidResponse, Bid, PayerShare, BidHistoryResponse, Contribution, BiddingPeriodResponse, BidsResponse, DenylistResponse, HooksResponse, HookInfo, OperatorsResponse, OperatorInfo, PauseStatusResponse, PendingAdminTransferResponse, AdminTransfer, PendingSettlementResponse, PendingSettlement, ProposalResponse, Proposal, ProposalsResponse, ReceiptResponse, Receipt, RolesResponse, ScheduleResponse, Schedule, SimulateBidResponse, SimulateEndBiddingResponse, Payout, StatsResponse, PeriodStats, RankedBid, TreasuryResponse } from "./Bid.types";
 export interface BidReadOnlyInterface {
   contractAddress: string;
   biddingPeriod: () => Promise<BiddingPeriodResponse>;
@@ -16,6 +16,82 @@ export interface BidReadOnlyInterface {
   }: {
     address: Addr;
   }) => Promise<BidResponse>;
+  schedule: () => Promise<ScheduleResponse>;
+  pendingSettlement: () => Promise<PendingSettlementResponse>;
+  pauseStatus: () => Promise<PauseStatusResponse>;
+  stats: () => Promise<StatsResponse>;
+  simulateBid: ({
+    address,
+    amount
+  }: {
+    address: Addr;
+    amount: Uint128;
+  }) => Promise<SimulateBidResponse>;
+  simulateEndBidding: ({
+    acceptedBids,
+    sender,
+    withdrawalAddress
+  }: {
+    acceptedBids: Addr[];
+    sender?: Addr;
+    withdrawalAddress?: Addr;
+  }) => Promise<SimulateEndBiddingResponse>;
+  bidHistory: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: Addr;
+    limit?: number;
+    startAfter?: number;
+  }) => Promise<BidHistoryResponse>;
+  denylist: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }) => Promise<DenylistResponse>;
+  receipt: ({
+    address,
+    periodId
+  }: {
+    address: Addr;
+    periodId: number;
+  }) => Promise<ReceiptResponse>;
+  hooks: () => Promise<HooksResponse>;
+  pendingAdminTransfer: () => Promise<PendingAdminTransferResponse>;
+  roles: ({
+    address
+  }: {
+    address: Addr;
+  }) => Promise<RolesResponse>;
+  treasury: () => Promise<TreasuryResponse>;
+  proposal: ({
+    proposalId
+  }: {
+    proposalId: number;
+  }) => Promise<ProposalResponse>;
+  proposals: ({
+    limit,
+    pending,
+    startAfter
+  }: {
+    limit?: number;
+    pending?: boolean;
+    startAfter?: number;
+  }) => Promise<ProposalsResponse>;
+  operators: ({
+    includeExpired,
+    limit,
+    owner,
+    startAfter
+  }: {
+    includeExpired?: boolean;
+    limit?: number;
+    owner: Addr;
+    startAfter?: Addr;
+  }) => Promise<OperatorsResponse>;
 }
 export class BidQueryClient implements BidReadOnlyInterface {
   client: CosmWasmClient;
@@ -27,6 +103,22 @@ export class BidQueryClient implements BidReadOnlyInterface {
     this.biddingPeriod = this.biddingPeriod.bind(this);
     this.bids = this.bids.bind(this);
     this.bid = this.bid.bind(this);
+    this.schedule = this.schedule.bind(this);
+    this.pendingSettlement = this.pendingSettlement.bind(this);
+    this.pauseStatus = this.pauseStatus.bind(this);
+    this.stats = this.stats.bind(this);
+    this.simulateBid = this.simulateBid.bind(this);
+    this.simulateEndBidding = this.simulateEndBidding.bind(this);
+    this.bidHistory = this.bidHistory.bind(this);
+    this.denylist = this.denylist.bind(this);
+    this.receipt = this.receipt.bind(this);
+    this.hooks = this.hooks.bind(this);
+    this.pendingAdminTransfer = this.pendingAdminTransfer.bind(this);
+    this.roles = this.roles.bind(this);
+    this.treasury = this.treasury.bind(this);
+    this.proposal = this.proposal.bind(this);
+    this.proposals = this.proposals.bind(this);
+    this.operators = this.operators.bind(this);
   }
 
   biddingPeriod = async (): Promise<BiddingPeriodResponse> => {
@@ -50,6 +142,176 @@ export class BidQueryClient implements BidReadOnlyInterface {
       }
     });
   };
+  schedule = async (): Promise<ScheduleResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      schedule: {}
+    });
+  };
+  pendingSettlement = async (): Promise<PendingSettlementResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_settlement: {}
+    });
+  };
+  pauseStatus = async (): Promise<PauseStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pause_status: {}
+    });
+  };
+  stats = async (): Promise<StatsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      stats: {}
+    });
+  };
+  simulateBid = async ({
+    address,
+    amount
+  }: {
+    address: Addr;
+    amount: Uint128;
+  }): Promise<SimulateBidResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      simulate_bid: {
+        address,
+        amount
+      }
+    });
+  };
+  simulateEndBidding = async ({
+    acceptedBids,
+    sender,
+    withdrawalAddress
+  }: {
+    acceptedBids: Addr[];
+    sender?: Addr;
+    withdrawalAddress?: Addr;
+  }): Promise<SimulateEndBiddingResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      simulate_end_bidding: {
+        accepted_bids: acceptedBids,
+        sender,
+        withdrawal_address: withdrawalAddress
+      }
+    });
+  };
+  bidHistory = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: Addr;
+    limit?: number;
+    startAfter?: number;
+  }): Promise<BidHistoryResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bid_history: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  denylist = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: Addr;
+  }): Promise<DenylistResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      denylist: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  receipt = async ({
+    address,
+    periodId
+  }: {
+    address: Addr;
+    periodId: number;
+  }): Promise<ReceiptResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      receipt: {
+        address,
+        period_id: periodId
+      }
+    });
+  };
+  hooks = async (): Promise<HooksResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      hooks: {}
+    });
+  };
+  pendingAdminTransfer = async (): Promise<PendingAdminTransferResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pending_admin_transfer: {}
+    });
+  };
+  roles = async ({
+    address
+  }: {
+    address: Addr;
+  }): Promise<RolesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      roles: {
+        address
+      }
+    });
+  };
+  treasury = async (): Promise<TreasuryResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      treasury: {}
+    });
+  };
+  proposal = async ({
+    proposalId
+  }: {
+    proposalId: number;
+  }): Promise<ProposalResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      proposal: {
+        proposal_id: proposalId
+      }
+    });
+  };
+  proposals = async ({
+    limit,
+    pending,
+    startAfter
+  }: {
+    limit?: number;
+    pending?: boolean;
+    startAfter?: number;
+  }): Promise<ProposalsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      proposals: {
+        limit,
+        pending,
+        start_after: startAfter
+      }
+    });
+  };
+  operators = async ({
+    includeExpired,
+    limit,
+    owner,
+    startAfter
+  }: {
+    includeExpired?: boolean;
+    limit?: number;
+    owner: Addr;
+    startAfter?: Addr;
+  }): Promise<OperatorsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      operators: {
+        include_expired: includeExpired,
+        limit,
+        owner,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface BidInterface extends BidReadOnlyInterface {
   contractAddress: string;
@@ -66,11 +328,140 @@ export interface BidInterface extends BidReadOnlyInterface {
     acceptedBids: Addr[];
     withdrawalAddress?: Addr;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateBiddingPeriod: ({
+    config
+  }: {
+    config: BiddingPeriod;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  createSchedule: ({
+    count,
+    interval,
+    template
+  }: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelSchedule: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelBidding: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  grantRole: ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeRole: ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setTreasury: ({
+    address
+  }: {
+    address?: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setProceedsSplit: ({
+    split
+  }: {
+    split: ProceedsShare[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  proposeAdminTransfer: ({
+    expiry,
+    newAdmin
+  }: {
+    expiry: Expiration;
+    newAdmin: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  acceptAdminTransfer: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelAdminTransfer: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  propose: ({
+    action,
+    expires
+  }: {
+    action: ProposalAction;
+    expires: Expiration;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  approve: ({
+    proposalId
+  }: {
+    proposalId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  disqualifyBid: ({
+    address,
+    denylist,
+    reason
+  }: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateDenylist: ({
+    add,
+    remove
+  }: {
+    add: Addr[];
+    remove: Addr[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addHook: ({
+    address,
+    gasLimit,
+    onError
+  }: {
+    address: Addr;
+    gasLimit?: number;
+    onError: HookFailure;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeHook: ({
+    address
+  }: {
+    address: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  vetoSettlement: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  pause: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  unpause: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   bid: ({
+    address,
+    attestation,
+    termsHash
+  }: {
+    address?: Addr;
+    attestation?: Attestation;
+    termsHash?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  emergencyWithdraw: ({
     address
   }: {
     address?: Addr;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  transferBid: ({
+    from,
+    to
+  }: {
+    from?: Addr;
+    to: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  approveOperator: ({
+    expires,
+    operator
+  }: {
+    expires?: Expiration;
+    operator: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeOperator: ({
+    operator
+  }: {
+    operator: Addr;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  claimReceipt: ({
+    periodId
+  }: {
+    periodId: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  startScheduledBidding: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  releaseProceeds: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class BidClient extends BidQueryClient implements BidInterface {
   client: SigningCosmWasmClient;
@@ -84,7 +475,34 @@ export class BidClient extends BidQueryClient implements BidInterface {
     this.contractAddress = contractAddress;
     this.startBidding = this.startBidding.bind(this);
     this.endBidding = this.endBidding.bind(this);
+    this.updateBiddingPeriod = this.updateBiddingPeriod.bind(this);
+    this.createSchedule = this.createSchedule.bind(this);
+    this.cancelSchedule = this.cancelSchedule.bind(this);
+    this.cancelBidding = this.cancelBidding.bind(this);
+    this.grantRole = this.grantRole.bind(this);
+    this.revokeRole = this.revokeRole.bind(this);
+    this.setTreasury = this.setTreasury.bind(this);
+    this.setProceedsSplit = this.setProceedsSplit.bind(this);
+    this.proposeAdminTransfer = this.proposeAdminTransfer.bind(this);
+    this.acceptAdminTransfer = this.acceptAdminTransfer.bind(this);
+    this.cancelAdminTransfer = this.cancelAdminTransfer.bind(this);
+    this.propose = this.propose.bind(this);
+    this.approve = this.approve.bind(this);
+    this.disqualifyBid = this.disqualifyBid.bind(this);
+    this.updateDenylist = this.updateDenylist.bind(this);
+    this.addHook = this.addHook.bind(this);
+    this.removeHook = this.removeHook.bind(this);
+    this.vetoSettlement = this.vetoSettlement.bind(this);
+    this.pause = this.pause.bind(this);
+    this.unpause = this.unpause.bind(this);
     this.bid = this.bid.bind(this);
+    this.emergencyWithdraw = this.emergencyWithdraw.bind(this);
+    this.transferBid = this.transferBid.bind(this);
+    this.approveOperator = this.approveOperator.bind(this);
+    this.revokeOperator = this.revokeOperator.bind(this);
+    this.claimReceipt = this.claimReceipt.bind(this);
+    this.startScheduledBidding = this.startScheduledBidding.bind(this);
+    this.releaseProceeds = this.releaseProceeds.bind(this);
   }
 
   startBidding = async ({
@@ -112,15 +530,303 @@ export class BidClient extends BidQueryClient implements BidInterface {
       }
     }, fee, memo, funds);
   };
-  bid = async ({
+  updateBiddingPeriod = async ({
+    config
+  }: {
+    config: BiddingPeriod;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_bidding_period: {
+        config
+      }
+    }, fee, memo, funds);
+  };
+  createSchedule = async ({
+    count,
+    interval,
+    template
+  }: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      create_schedule: {
+        count,
+        interval,
+        template
+      }
+    }, fee, memo, funds);
+  };
+  cancelSchedule = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_schedule: {}
+    }, fee, memo, funds);
+  };
+  cancelBidding = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_bidding: {}
+    }, fee, memo, funds);
+  };
+  grantRole = async ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      grant_role: {
+        address,
+        role
+      }
+    }, fee, memo, funds);
+  };
+  revokeRole = async ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_role: {
+        address,
+        role
+      }
+    }, fee, memo, funds);
+  };
+  setTreasury = async ({
     address
   }: {
     address?: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_treasury: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  setProceedsSplit = async ({
+    split
+  }: {
+    split: ProceedsShare[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_proceeds_split: {
+        split
+      }
+    }, fee, memo, funds);
+  };
+  proposeAdminTransfer = async ({
+    expiry,
+    newAdmin
+  }: {
+    expiry: Expiration;
+    newAdmin: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      propose_admin_transfer: {
+        expiry,
+        new_admin: newAdmin
+      }
+    }, fee, memo, funds);
+  };
+  acceptAdminTransfer = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      accept_admin_transfer: {}
+    }, fee, memo, funds);
+  };
+  cancelAdminTransfer = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_admin_transfer: {}
+    }, fee, memo, funds);
+  };
+  propose = async ({
+    action,
+    expires
+  }: {
+    action: ProposalAction;
+    expires: Expiration;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      propose: {
+        action,
+        expires
+      }
+    }, fee, memo, funds);
+  };
+  approve = async ({
+    proposalId
+  }: {
+    proposalId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      approve: {
+        proposal_id: proposalId
+      }
+    }, fee, memo, funds);
+  };
+  disqualifyBid = async ({
+    address,
+    denylist,
+    reason
+  }: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      disqualify_bid: {
+        address,
+        denylist,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  updateDenylist = async ({
+    add,
+    remove
+  }: {
+    add: Addr[];
+    remove: Addr[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_denylist: {
+        add,
+        remove
+      }
+    }, fee, memo, funds);
+  };
+  addHook = async ({
+    address,
+    gasLimit,
+    onError
+  }: {
+    address: Addr;
+    gasLimit?: number;
+    onError: HookFailure;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_hook: {
+        address,
+        gas_limit: gasLimit,
+        on_error: onError
+      }
+    }, fee, memo, funds);
+  };
+  removeHook = async ({
+    address
+  }: {
+    address: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_hook: {
+        address
+      }
+    }, fee, memo, funds);
+  };
+  vetoSettlement = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      veto_settlement: {}
+    }, fee, memo, funds);
+  };
+  pause = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      pause: {}
+    }, fee, memo, funds);
+  };
+  unpause = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unpause: {}
+    }, fee, memo, funds);
+  };
+  bid = async ({
+    address,
+    attestation,
+    termsHash
+  }: {
+    address?: Addr;
+    attestation?: Attestation;
+    termsHash?: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       bid: {
+        address,
+        attestation,
+        terms_hash: termsHash
+      }
+    }, fee, memo, funds);
+  };
+  emergencyWithdraw = async ({
+    address
+  }: {
+    address?: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      emergency_withdraw: {
         address
       }
     }, fee, memo, funds);
   };
+  transferBid = async ({
+    from,
+    to
+  }: {
+    from?: Addr;
+    to: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      transfer_bid: {
+        from,
+        to
+      }
+    }, fee, memo, funds);
+  };
+  approveOperator = async ({
+    expires,
+    operator
+  }: {
+    expires?: Expiration;
+    operator: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      approve_operator: {
+        expires,
+        operator
+      }
+    }, fee, memo, funds);
+  };
+  revokeOperator = async ({
+    operator
+  }: {
+    operator: Addr;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_operator: {
+        operator
+      }
+    }, fee, memo, funds);
+  };
+  claimReceipt = async ({
+    periodId
+  }: {
+    periodId: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_receipt: {
+        period_id: periodId
+      }
+    }, fee, memo, funds);
+  };
+  startScheduledBidding = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      start_scheduled_bidding: {}
+    }, fee, memo, funds);
+  };
+  releaseProceeds = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      release_proceeds: {}
+    }, fee, memo, funds);
+  };
 }
\ No newline at end of file
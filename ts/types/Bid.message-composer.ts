@@ -8,7 +8,7 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Addr, InstantiateMsg, ExecuteMsg, Timestamp, Uint64, Uint128, BiddingPeriod, QueryMsg, BidResponse, Bid, BiddingPeriodResponse, BidsResponse } from "./Bid.types";
+import { Addr, InstantiateMsg, ExecuteMsg, Attestor, Binary, Expiration, Timestamp, Uint64, Uint128, RefundTarget, Scheduled, Duration, Role, ProposalAction, HookFailure, BiddingPeriod, DisputeWindow, GroupRestriction, PeriodMetadata, ProceedsShare, Attestation, QueryMsg, MigrateMsg, SudoMsg, BidResponse, Bid, PayerShare, BidHistoryResponse, Contribution, BiddingPeriodResponse, BidsResponse, DenylistResponse, HooksResponse, HookInfo, OperatorsResponse, OperatorInfo, PauseStatusResponse, PendingAdminTransferResponse, AdminTransfer, PendingSettlementResponse, PendingSettlement, ProposalResponse, Proposal, ProposalsResponse, ReceiptResponse, Receipt, RolesResponse, ScheduleResponse, Schedule, SimulateBidResponse, SimulateEndBiddingResponse, Payout, StatsResponse, PeriodStats, RankedBid, TreasuryResponse } from "./Bid.types";
 export interface BidMessage {
   contractAddress: string;
   sender: string;
@@ -24,11 +24,140 @@ export interface BidMessage {
     acceptedBids: Addr[];
     withdrawalAddress?: Addr;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateBiddingPeriod: ({
+    config
+  }: {
+    config: BiddingPeriod;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  createSchedule: ({
+    count,
+    interval,
+    template
+  }: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelSchedule: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelBidding: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  grantRole: ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeRole: ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setTreasury: ({
+    address
+  }: {
+    address?: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setProceedsSplit: ({
+    split
+  }: {
+    split: ProceedsShare[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  proposeAdminTransfer: ({
+    expiry,
+    newAdmin
+  }: {
+    expiry: Expiration;
+    newAdmin: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  acceptAdminTransfer: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelAdminTransfer: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  propose: ({
+    action,
+    expires
+  }: {
+    action: ProposalAction;
+    expires: Expiration;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  approve: ({
+    proposalId
+  }: {
+    proposalId: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  disqualifyBid: ({
+    address,
+    denylist,
+    reason
+  }: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateDenylist: ({
+    add,
+    remove
+  }: {
+    add: Addr[];
+    remove: Addr[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addHook: ({
+    address,
+    gasLimit,
+    onError
+  }: {
+    address: Addr;
+    gasLimit?: number;
+    onError: HookFailure;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeHook: ({
+    address
+  }: {
+    address: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  vetoSettlement: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  pause: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  unpause: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
   bid: ({
+    address,
+    attestation,
+    termsHash
+  }: {
+    address?: Addr;
+    attestation?: Attestation;
+    termsHash?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  emergencyWithdraw: ({
     address
   }: {
     address?: Addr;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  transferBid: ({
+    from,
+    to
+  }: {
+    from?: Addr;
+    to: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  approveOperator: ({
+    expires,
+    operator
+  }: {
+    expires?: Expiration;
+    operator: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeOperator: ({
+    operator
+  }: {
+    operator: Addr;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimReceipt: ({
+    periodId
+  }: {
+    periodId: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  startScheduledBidding: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  releaseProceeds: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class BidMessageComposer implements BidMessage {
   sender: string;
@@ -39,7 +168,34 @@ export class BidMessageComposer implements BidMessage {
     this.contractAddress = contractAddress;
     this.startBidding = this.startBidding.bind(this);
     this.endBidding = this.endBidding.bind(this);
+    this.updateBiddingPeriod = this.updateBiddingPeriod.bind(this);
+    this.createSchedule = this.createSchedule.bind(this);
+    this.cancelSchedule = this.cancelSchedule.bind(this);
+    this.cancelBidding = this.cancelBidding.bind(this);
+    this.grantRole = this.grantRole.bind(this);
+    this.revokeRole = this.revokeRole.bind(this);
+    this.setTreasury = this.setTreasury.bind(this);
+    this.setProceedsSplit = this.setProceedsSplit.bind(this);
+    this.proposeAdminTransfer = this.proposeAdminTransfer.bind(this);
+    this.acceptAdminTransfer = this.acceptAdminTransfer.bind(this);
+    this.cancelAdminTransfer = this.cancelAdminTransfer.bind(this);
+    this.propose = this.propose.bind(this);
+    this.approve = this.approve.bind(this);
+    this.disqualifyBid = this.disqualifyBid.bind(this);
+    this.updateDenylist = this.updateDenylist.bind(this);
+    this.addHook = this.addHook.bind(this);
+    this.removeHook = this.removeHook.bind(this);
+    this.vetoSettlement = this.vetoSettlement.bind(this);
+    this.pause = this.pause.bind(this);
+    this.unpause = this.unpause.bind(this);
     this.bid = this.bid.bind(this);
+    this.emergencyWithdraw = this.emergencyWithdraw.bind(this);
+    this.transferBid = this.transferBid.bind(this);
+    this.approveOperator = this.approveOperator.bind(this);
+    this.revokeOperator = this.revokeOperator.bind(this);
+    this.claimReceipt = this.claimReceipt.bind(this);
+    this.startScheduledBidding = this.startScheduledBidding.bind(this);
+    this.releaseProceeds = this.releaseProceeds.bind(this);
   }
 
   startBidding = ({
@@ -83,10 +239,385 @@ export class BidMessageComposer implements BidMessage {
       })
     };
   };
-  bid = ({
+  updateBiddingPeriod = ({
+    config
+  }: {
+    config: BiddingPeriod;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_bidding_period: {
+            config
+          }
+        })),
+        funds
+      })
+    };
+  };
+  createSchedule = ({
+    count,
+    interval,
+    template
+  }: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          create_schedule: {
+            count,
+            interval,
+            template
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelSchedule = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_schedule: {}
+        })),
+        funds
+      })
+    };
+  };
+  cancelBidding = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_bidding: {}
+        })),
+        funds
+      })
+    };
+  };
+  grantRole = ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          grant_role: {
+            address,
+            role
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeRole = ({
+    address,
+    role
+  }: {
+    address: Addr;
+    role: Role;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_role: {
+            address,
+            role
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setTreasury = ({
+    address
+  }: {
+    address?: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_treasury: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setProceedsSplit = ({
+    split
+  }: {
+    split: ProceedsShare[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_proceeds_split: {
+            split
+          }
+        })),
+        funds
+      })
+    };
+  };
+  proposeAdminTransfer = ({
+    expiry,
+    newAdmin
+  }: {
+    expiry: Expiration;
+    newAdmin: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          propose_admin_transfer: {
+            expiry,
+            new_admin: newAdmin
+          }
+        })),
+        funds
+      })
+    };
+  };
+  acceptAdminTransfer = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          accept_admin_transfer: {}
+        })),
+        funds
+      })
+    };
+  };
+  cancelAdminTransfer = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_admin_transfer: {}
+        })),
+        funds
+      })
+    };
+  };
+  propose = ({
+    action,
+    expires
+  }: {
+    action: ProposalAction;
+    expires: Expiration;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          propose: {
+            action,
+            expires
+          }
+        })),
+        funds
+      })
+    };
+  };
+  approve = ({
+    proposalId
+  }: {
+    proposalId: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          approve: {
+            proposal_id: proposalId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  disqualifyBid = ({
+    address,
+    denylist,
+    reason
+  }: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          disqualify_bid: {
+            address,
+            denylist,
+            reason
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateDenylist = ({
+    add,
+    remove
+  }: {
+    add: Addr[];
+    remove: Addr[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_denylist: {
+            add,
+            remove
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addHook = ({
+    address,
+    gasLimit,
+    onError
+  }: {
+    address: Addr;
+    gasLimit?: number;
+    onError: HookFailure;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_hook: {
+            address,
+            gas_limit: gasLimit,
+            on_error: onError
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeHook = ({
     address
+  }: {
+    address: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_hook: {
+            address
+          }
+        })),
+        funds
+      })
+    };
+  };
+  vetoSettlement = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          veto_settlement: {}
+        })),
+        funds
+      })
+    };
+  };
+  pause = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          pause: {}
+        })),
+        funds
+      })
+    };
+  };
+  unpause = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          unpause: {}
+        })),
+        funds
+      })
+    };
+  };
+  bid = ({
+    address,
+    attestation,
+    termsHash
   }: {
     address?: Addr;
+    attestation?: Attestation;
+    termsHash?: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -95,6 +626,27 @@ export class BidMessageComposer implements BidMessage {
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           bid: {
+            address,
+            attestation,
+            terms_hash: termsHash
+          }
+        })),
+        funds
+      })
+    };
+  };
+  emergencyWithdraw = ({
+    address
+  }: {
+    address?: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          emergency_withdraw: {
             address
           }
         })),
@@ -102,4 +654,112 @@ export class BidMessageComposer implements BidMessage {
       })
     };
   };
+  transferBid = ({
+    from,
+    to
+  }: {
+    from?: Addr;
+    to: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          transfer_bid: {
+            from,
+            to
+          }
+        })),
+        funds
+      })
+    };
+  };
+  approveOperator = ({
+    expires,
+    operator
+  }: {
+    expires?: Expiration;
+    operator: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          approve_operator: {
+            expires,
+            operator
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeOperator = ({
+    operator
+  }: {
+    operator: Addr;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_operator: {
+            operator
+          }
+        })),
+        funds
+      })
+    };
+  };
+  claimReceipt = ({
+    periodId
+  }: {
+    periodId: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_receipt: {
+            period_id: periodId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  startScheduledBidding = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          start_scheduled_bidding: {}
+        })),
+        funds
+      })
+    };
+  };
+  releaseProceeds = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          release_proceeds: {}
+        })),
+        funds
+      })
+    };
+  };
 }
\ No newline at end of file
@@ -7,7 +7,7 @@
 import { UseQueryOptions, useQuery, useMutation, UseMutationOptions } from "@tanstack/react-query";
 import { ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee, Coin } from "@cosmjs/amino";
-import { Addr, InstantiateMsg, ExecuteMsg, Timestamp, Uint64, Uint128, BiddingPeriod, QueryMsg, BidResponse, Bid, BiddingPeriodResponse, BidsResponse } from "./Bid.types";
+import { Addr, InstantiateMsg, ExecuteMsg, Attestor, Binary, Expiration, Timestamp, Uint64, Uint128, RefundTarget, Scheduled, Duration, Role, ProposalAction, HookFailure, BiddingPeriod, DisputeWindow, GroupRestriction, PeriodMetadata, ProceedsShare, Attestation, QueryMsg, MigrateMsg, SudoMsg, BidResponse, Bid, PayerShare, BidHistoryResponse, Contribution, BiddingPeriodResponse, BidsResponse, DenylistResponse, HooksResponse, HookInfo, OperatorsResponse, OperatorInfo, PauseStatusResponse, PendingAdminTransferResponse, AdminTransfer, PendingSettlementResponse, PendingSettlement, ProposalResponse, Proposal, ProposalsResponse, ReceiptResponse, Receipt, RolesResponse, ScheduleResponse, Schedule, SimulateBidResponse, SimulateEndBiddingResponse, Payout, StatsResponse, PeriodStats, RankedBid, TreasuryResponse } from "./Bid.types";
 import { BidQueryClient, BidClient } from "./Bid.client";
 export const bidQueryKeys = {
   contract: ([{
@@ -27,6 +27,70 @@ export const bidQueryKeys = {
   bid: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
     method: "bid",
     args
+  }] as const),
+  schedule: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "schedule",
+    args
+  }] as const),
+  pendingSettlement: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "pending_settlement",
+    args
+  }] as const),
+  pauseStatus: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "pause_status",
+    args
+  }] as const),
+  stats: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "stats",
+    args
+  }] as const),
+  simulateBid: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "simulate_bid",
+    args
+  }] as const),
+  simulateEndBidding: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "simulate_end_bidding",
+    args
+  }] as const),
+  bidHistory: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "bid_history",
+    args
+  }] as const),
+  denylist: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "denylist",
+    args
+  }] as const),
+  receipt: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "receipt",
+    args
+  }] as const),
+  hooks: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "hooks",
+    args
+  }] as const),
+  pendingAdminTransfer: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "pending_admin_transfer",
+    args
+  }] as const),
+  roles: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "roles",
+    args
+  }] as const),
+  treasury: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "treasury",
+    args
+  }] as const),
+  proposal: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "proposal",
+    args
+  }] as const),
+  proposals: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "proposals",
+    args
+  }] as const),
+  operators: (contractAddress: string | undefined, args?: Record<string, unknown>) => ([{ ...bidQueryKeys.address(contractAddress)[0],
+    method: "operators",
+    args
   }] as const)
 };
 export interface BidReactQuery<TResponse, TData = TResponse> {
@@ -35,6 +99,237 @@ export interface BidReactQuery<TResponse, TData = TResponse> {
     initialData?: undefined;
   };
 }
+export interface BidOperatorsQuery<TData> extends BidReactQuery<OperatorsResponse, TData> {
+  args: {
+    includeExpired?: boolean;
+    limit?: number;
+    owner: Addr;
+    startAfter?: Addr;
+  };
+}
+export function useBidOperatorsQuery<TData = OperatorsResponse>({
+  client,
+  args,
+  options
+}: BidOperatorsQuery<TData>) {
+  return useQuery<OperatorsResponse, Error, TData>(bidQueryKeys.operators(client?.contractAddress, args), () => client ? client.operators({
+    includeExpired: args.includeExpired,
+    limit: args.limit,
+    owner: args.owner,
+    startAfter: args.startAfter
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidProposalsQuery<TData> extends BidReactQuery<ProposalsResponse, TData> {
+  args: {
+    limit?: number;
+    pending?: boolean;
+    startAfter?: number;
+  };
+}
+export function useBidProposalsQuery<TData = ProposalsResponse>({
+  client,
+  args,
+  options
+}: BidProposalsQuery<TData>) {
+  return useQuery<ProposalsResponse, Error, TData>(bidQueryKeys.proposals(client?.contractAddress, args), () => client ? client.proposals({
+    limit: args.limit,
+    pending: args.pending,
+    startAfter: args.startAfter
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidProposalQuery<TData> extends BidReactQuery<ProposalResponse, TData> {
+  args: {
+    proposalId: number;
+  };
+}
+export function useBidProposalQuery<TData = ProposalResponse>({
+  client,
+  args,
+  options
+}: BidProposalQuery<TData>) {
+  return useQuery<ProposalResponse, Error, TData>(bidQueryKeys.proposal(client?.contractAddress, args), () => client ? client.proposal({
+    proposalId: args.proposalId
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidTreasuryQuery<TData> extends BidReactQuery<TreasuryResponse, TData> {}
+export function useBidTreasuryQuery<TData = TreasuryResponse>({
+  client,
+  options
+}: BidTreasuryQuery<TData>) {
+  return useQuery<TreasuryResponse, Error, TData>(bidQueryKeys.treasury(client?.contractAddress), () => client ? client.treasury() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidRolesQuery<TData> extends BidReactQuery<RolesResponse, TData> {
+  args: {
+    address: Addr;
+  };
+}
+export function useBidRolesQuery<TData = RolesResponse>({
+  client,
+  args,
+  options
+}: BidRolesQuery<TData>) {
+  return useQuery<RolesResponse, Error, TData>(bidQueryKeys.roles(client?.contractAddress, args), () => client ? client.roles({
+    address: args.address
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidPendingAdminTransferQuery<TData> extends BidReactQuery<PendingAdminTransferResponse, TData> {}
+export function useBidPendingAdminTransferQuery<TData = PendingAdminTransferResponse>({
+  client,
+  options
+}: BidPendingAdminTransferQuery<TData>) {
+  return useQuery<PendingAdminTransferResponse, Error, TData>(bidQueryKeys.pendingAdminTransfer(client?.contractAddress), () => client ? client.pendingAdminTransfer() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidHooksQuery<TData> extends BidReactQuery<HooksResponse, TData> {}
+export function useBidHooksQuery<TData = HooksResponse>({
+  client,
+  options
+}: BidHooksQuery<TData>) {
+  return useQuery<HooksResponse, Error, TData>(bidQueryKeys.hooks(client?.contractAddress), () => client ? client.hooks() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidReceiptQuery<TData> extends BidReactQuery<ReceiptResponse, TData> {
+  args: {
+    address: Addr;
+    periodId: number;
+  };
+}
+export function useBidReceiptQuery<TData = ReceiptResponse>({
+  client,
+  args,
+  options
+}: BidReceiptQuery<TData>) {
+  return useQuery<ReceiptResponse, Error, TData>(bidQueryKeys.receipt(client?.contractAddress, args), () => client ? client.receipt({
+    address: args.address,
+    periodId: args.periodId
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidDenylistQuery<TData> extends BidReactQuery<DenylistResponse, TData> {
+  args: {
+    limit?: number;
+    startAfter?: Addr;
+  };
+}
+export function useBidDenylistQuery<TData = DenylistResponse>({
+  client,
+  args,
+  options
+}: BidDenylistQuery<TData>) {
+  return useQuery<DenylistResponse, Error, TData>(bidQueryKeys.denylist(client?.contractAddress, args), () => client ? client.denylist({
+    limit: args.limit,
+    startAfter: args.startAfter
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidBidHistoryQuery<TData> extends BidReactQuery<BidHistoryResponse, TData> {
+  args: {
+    address: Addr;
+    limit?: number;
+    startAfter?: number;
+  };
+}
+export function useBidBidHistoryQuery<TData = BidHistoryResponse>({
+  client,
+  args,
+  options
+}: BidBidHistoryQuery<TData>) {
+  return useQuery<BidHistoryResponse, Error, TData>(bidQueryKeys.bidHistory(client?.contractAddress, args), () => client ? client.bidHistory({
+    address: args.address,
+    limit: args.limit,
+    startAfter: args.startAfter
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidSimulateEndBiddingQuery<TData> extends BidReactQuery<SimulateEndBiddingResponse, TData> {
+  args: {
+    acceptedBids: Addr[];
+    sender?: Addr;
+    withdrawalAddress?: Addr;
+  };
+}
+export function useBidSimulateEndBiddingQuery<TData = SimulateEndBiddingResponse>({
+  client,
+  args,
+  options
+}: BidSimulateEndBiddingQuery<TData>) {
+  return useQuery<SimulateEndBiddingResponse, Error, TData>(bidQueryKeys.simulateEndBidding(client?.contractAddress, args), () => client ? client.simulateEndBidding({
+    acceptedBids: args.acceptedBids,
+    sender: args.sender,
+    withdrawalAddress: args.withdrawalAddress
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidSimulateBidQuery<TData> extends BidReactQuery<SimulateBidResponse, TData> {
+  args: {
+    address: Addr;
+    amount: Uint128;
+  };
+}
+export function useBidSimulateBidQuery<TData = SimulateBidResponse>({
+  client,
+  args,
+  options
+}: BidSimulateBidQuery<TData>) {
+  return useQuery<SimulateBidResponse, Error, TData>(bidQueryKeys.simulateBid(client?.contractAddress, args), () => client ? client.simulateBid({
+    address: args.address,
+    amount: args.amount
+  }) : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidStatsQuery<TData> extends BidReactQuery<StatsResponse, TData> {}
+export function useBidStatsQuery<TData = StatsResponse>({
+  client,
+  options
+}: BidStatsQuery<TData>) {
+  return useQuery<StatsResponse, Error, TData>(bidQueryKeys.stats(client?.contractAddress), () => client ? client.stats() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidPauseStatusQuery<TData> extends BidReactQuery<PauseStatusResponse, TData> {}
+export function useBidPauseStatusQuery<TData = PauseStatusResponse>({
+  client,
+  options
+}: BidPauseStatusQuery<TData>) {
+  return useQuery<PauseStatusResponse, Error, TData>(bidQueryKeys.pauseStatus(client?.contractAddress), () => client ? client.pauseStatus() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidPendingSettlementQuery<TData> extends BidReactQuery<PendingSettlementResponse, TData> {}
+export function useBidPendingSettlementQuery<TData = PendingSettlementResponse>({
+  client,
+  options
+}: BidPendingSettlementQuery<TData>) {
+  return useQuery<PendingSettlementResponse, Error, TData>(bidQueryKeys.pendingSettlement(client?.contractAddress), () => client ? client.pendingSettlement() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
+export interface BidScheduleQuery<TData> extends BidReactQuery<ScheduleResponse, TData> {}
+export function useBidScheduleQuery<TData = ScheduleResponse>({
+  client,
+  options
+}: BidScheduleQuery<TData>) {
+  return useQuery<ScheduleResponse, Error, TData>(bidQueryKeys.schedule(client?.contractAddress), () => client ? client.schedule() : Promise.reject(new Error("Invalid client")), { ...options,
+    enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
+  });
+}
 export interface BidBidQuery<TData> extends BidReactQuery<BidResponse, TData> {
   args: {
     address: Addr;
@@ -69,10 +364,46 @@ export function useBidBiddingPeriodQuery<TData = BiddingPeriodResponse>({
     enabled: !!client && (options?.enabled != undefined ? options.enabled : true)
   });
 }
-export interface BidBidMutation {
+export interface BidReleaseProceedsMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidReleaseProceedsMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidReleaseProceedsMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidReleaseProceedsMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.releaseProceeds(fee, memo, funds), options);
+}
+export interface BidStartScheduledBiddingMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidStartScheduledBiddingMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidStartScheduledBiddingMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidStartScheduledBiddingMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.startScheduledBidding(fee, memo, funds), options);
+}
+export interface BidClaimReceiptMutation {
   client: BidClient;
   msg: {
-    address?: Addr;
+    periodId: number;
   };
   args?: {
     fee?: number | StdFee | "auto";
@@ -80,8 +411,8 @@ export interface BidBidMutation {
     funds?: Coin[];
   };
 }
-export function useBidBidMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidBidMutation>, "mutationFn">) {
-  return useMutation<ExecuteResult, Error, BidBidMutation>(({
+export function useBidClaimReceiptMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidClaimReceiptMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidClaimReceiptMutation>(({
     client,
     msg,
     args: {
@@ -89,7 +420,544 @@ export function useBidBidMutation(options?: Omit<UseMutationOptions<ExecuteResul
       memo,
       funds
     } = {}
-  }) => client.bid(msg, fee, memo, funds), options);
+  }) => client.claimReceipt(msg, fee, memo, funds), options);
+}
+export interface BidRevokeOperatorMutation {
+  client: BidClient;
+  msg: {
+    operator: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidRevokeOperatorMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidRevokeOperatorMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidRevokeOperatorMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeOperator(msg, fee, memo, funds), options);
+}
+export interface BidApproveOperatorMutation {
+  client: BidClient;
+  msg: {
+    expires?: Expiration;
+    operator: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidApproveOperatorMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidApproveOperatorMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidApproveOperatorMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.approveOperator(msg, fee, memo, funds), options);
+}
+export interface BidTransferBidMutation {
+  client: BidClient;
+  msg: {
+    from?: Addr;
+    to: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidTransferBidMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidTransferBidMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidTransferBidMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.transferBid(msg, fee, memo, funds), options);
+}
+export interface BidEmergencyWithdrawMutation {
+  client: BidClient;
+  msg: {
+    address?: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidEmergencyWithdrawMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidEmergencyWithdrawMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidEmergencyWithdrawMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.emergencyWithdraw(msg, fee, memo, funds), options);
+}
+export interface BidBidMutation {
+  client: BidClient;
+  msg: {
+    address?: Addr;
+    attestation?: Attestation;
+    termsHash?: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidBidMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidBidMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidBidMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.bid(msg, fee, memo, funds), options);
+}
+export interface BidUnpauseMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidUnpauseMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidUnpauseMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidUnpauseMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.unpause(fee, memo, funds), options);
+}
+export interface BidPauseMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidPauseMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidPauseMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidPauseMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.pause(fee, memo, funds), options);
+}
+export interface BidVetoSettlementMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidVetoSettlementMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidVetoSettlementMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidVetoSettlementMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.vetoSettlement(fee, memo, funds), options);
+}
+export interface BidRemoveHookMutation {
+  client: BidClient;
+  msg: {
+    address: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidRemoveHookMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidRemoveHookMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidRemoveHookMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.removeHook(msg, fee, memo, funds), options);
+}
+export interface BidAddHookMutation {
+  client: BidClient;
+  msg: {
+    address: Addr;
+    gasLimit?: number;
+    onError: HookFailure;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidAddHookMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidAddHookMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidAddHookMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.addHook(msg, fee, memo, funds), options);
+}
+export interface BidUpdateDenylistMutation {
+  client: BidClient;
+  msg: {
+    add: Addr[];
+    remove: Addr[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidUpdateDenylistMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidUpdateDenylistMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidUpdateDenylistMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateDenylist(msg, fee, memo, funds), options);
+}
+export interface BidDisqualifyBidMutation {
+  client: BidClient;
+  msg: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidDisqualifyBidMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidDisqualifyBidMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidDisqualifyBidMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.disqualifyBid(msg, fee, memo, funds), options);
+}
+export interface BidApproveMutation {
+  client: BidClient;
+  msg: {
+    proposalId: number;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidApproveMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidApproveMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidApproveMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.approve(msg, fee, memo, funds), options);
+}
+export interface BidProposeMutation {
+  client: BidClient;
+  msg: {
+    action: ProposalAction;
+    expires: Expiration;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidProposeMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidProposeMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidProposeMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.propose(msg, fee, memo, funds), options);
+}
+export interface BidCancelAdminTransferMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidCancelAdminTransferMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidCancelAdminTransferMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidCancelAdminTransferMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelAdminTransfer(fee, memo, funds), options);
+}
+export interface BidAcceptAdminTransferMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidAcceptAdminTransferMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidAcceptAdminTransferMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidAcceptAdminTransferMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.acceptAdminTransfer(fee, memo, funds), options);
+}
+export interface BidProposeAdminTransferMutation {
+  client: BidClient;
+  msg: {
+    expiry: Expiration;
+    newAdmin: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidProposeAdminTransferMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidProposeAdminTransferMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidProposeAdminTransferMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.proposeAdminTransfer(msg, fee, memo, funds), options);
+}
+export interface BidSetProceedsSplitMutation {
+  client: BidClient;
+  msg: {
+    split: ProceedsShare[];
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidSetProceedsSplitMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidSetProceedsSplitMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidSetProceedsSplitMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.setProceedsSplit(msg, fee, memo, funds), options);
+}
+export interface BidSetTreasuryMutation {
+  client: BidClient;
+  msg: {
+    address?: Addr;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidSetTreasuryMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidSetTreasuryMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidSetTreasuryMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.setTreasury(msg, fee, memo, funds), options);
+}
+export interface BidRevokeRoleMutation {
+  client: BidClient;
+  msg: {
+    address: Addr;
+    role: Role;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidRevokeRoleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidRevokeRoleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidRevokeRoleMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.revokeRole(msg, fee, memo, funds), options);
+}
+export interface BidGrantRoleMutation {
+  client: BidClient;
+  msg: {
+    address: Addr;
+    role: Role;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidGrantRoleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidGrantRoleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidGrantRoleMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.grantRole(msg, fee, memo, funds), options);
+}
+export interface BidCancelBiddingMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidCancelBiddingMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidCancelBiddingMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidCancelBiddingMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelBidding(fee, memo, funds), options);
+}
+export interface BidCancelScheduleMutation {
+  client: BidClient;
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidCancelScheduleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidCancelScheduleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidCancelScheduleMutation>(({
+    client,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.cancelSchedule(fee, memo, funds), options);
+}
+export interface BidCreateScheduleMutation {
+  client: BidClient;
+  msg: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidCreateScheduleMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidCreateScheduleMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidCreateScheduleMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.createSchedule(msg, fee, memo, funds), options);
+}
+export interface BidUpdateBiddingPeriodMutation {
+  client: BidClient;
+  msg: {
+    config: BiddingPeriod;
+  };
+  args?: {
+    fee?: number | StdFee | "auto";
+    memo?: string;
+    funds?: Coin[];
+  };
+}
+export function useBidUpdateBiddingPeriodMutation(options?: Omit<UseMutationOptions<ExecuteResult, Error, BidUpdateBiddingPeriodMutation>, "mutationFn">) {
+  return useMutation<ExecuteResult, Error, BidUpdateBiddingPeriodMutation>(({
+    client,
+    msg,
+    args: {
+      fee,
+      memo,
+      funds
+    } = {}
+  }) => client.updateBiddingPeriod(msg, fee, memo, funds), options);
 }
 export interface BidEndBiddingMutation {
   client: BidClient;
@@ -7,6 +7,8 @@
 export type Addr = string;
 export interface InstantiateMsg {
   admins: Addr[];
+  emergency_withdraw_delay?: number | null;
+  threshold?: number | null;
 }
 export type ExecuteMsg = {
   start_bidding: {
@@ -17,21 +19,240 @@ export type ExecuteMsg = {
     accepted_bids: Addr[];
     withdrawal_address?: Addr | null;
   };
+} | {
+  update_bidding_period: {
+    config: BiddingPeriod;
+  };
+} | {
+  create_schedule: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  };
+} | {
+  cancel_schedule: {};
+} | {
+  cancel_bidding: {};
+} | {
+  grant_role: {
+    address: Addr;
+    role: Role;
+  };
+} | {
+  revoke_role: {
+    address: Addr;
+    role: Role;
+  };
+} | {
+  set_treasury: {
+    address?: Addr | null;
+  };
+} | {
+  set_proceeds_split: {
+    split: ProceedsShare[];
+  };
+} | {
+  propose_admin_transfer: {
+    expiry: Expiration;
+    new_admin: Addr;
+  };
+} | {
+  accept_admin_transfer: {};
+} | {
+  cancel_admin_transfer: {};
+} | {
+  propose: {
+    action: ProposalAction;
+    expires: Expiration;
+  };
+} | {
+  approve: {
+    proposal_id: number;
+  };
+} | {
+  disqualify_bid: {
+    address: Addr;
+    denylist: boolean;
+    reason: string;
+  };
+} | {
+  update_denylist: {
+    add: Addr[];
+    remove: Addr[];
+  };
+} | {
+  add_hook: {
+    address: Addr;
+    gas_limit?: number | null;
+    on_error: HookFailure;
+  };
+} | {
+  remove_hook: {
+    address: Addr;
+  };
+} | {
+  veto_settlement: {};
+} | {
+  pause: {};
+} | {
+  unpause: {};
 } | {
   bid: {
     address?: Addr | null;
+    attestation?: Attestation | null;
+    terms_hash?: string | null;
+  };
+} | {
+  emergency_withdraw: {
+    address?: Addr | null;
   };
+} | {
+  transfer_bid: {
+    from?: Addr | null;
+    to: Addr;
+  };
+} | {
+  approve_operator: {
+    expires?: Expiration | null;
+    operator: Addr;
+  };
+} | {
+  revoke_operator: {
+    operator: Addr;
+  };
+} | {
+  claim_receipt: {
+    period_id: number;
+  };
+} | {
+  start_scheduled_bidding: {};
+} | {
+  release_proceeds: {};
+};
+export type Attestor = {
+  secp256k1: {
+    pubkey: Binary;
+  };
+} | {
+  ed25519: {
+    pubkey: Binary;
+  };
+};
+export type Binary = string;
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {};
 };
 export type Timestamp = Uint64;
 export type Uint64 = string;
 export type Uint128 = string;
+export type RefundTarget = "beneficiary" | "payer";
+export type Scheduled = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+};
+export type Duration = {
+  height: number;
+} | {
+  time: number;
+};
+export type Role = "owner" | "operator" | "settler" | "treasurer";
+export type ProposalAction = {
+  start_bidding: {
+    config: BiddingPeriod;
+  };
+} | {
+  end_bidding: {
+    accepted_bids: Addr[];
+    withdrawal_address?: Addr | null;
+  };
+} | {
+  update_admins: {
+    admins: Addr[];
+    threshold: number;
+  };
+} | {
+  grant_role: {
+    address: Addr;
+    role: Role;
+  };
+} | {
+  revoke_role: {
+    address: Addr;
+    role: Role;
+  };
+} | {
+  update_denylist: {
+    add: Addr[];
+    remove: Addr[];
+  };
+} | {
+  set_treasury: {
+    address?: Addr | null;
+  };
+} | {
+  set_proceeds_split: {
+    split: ProceedsShare[];
+  };
+} | {
+  update_bidding_period: {
+    config: BiddingPeriod;
+  };
+} | {
+  create_schedule: {
+    count: number;
+    interval: Duration;
+    template: BiddingPeriod;
+  };
+};
+export type HookFailure = "ignore" | "revert";
 export interface BiddingPeriod {
   accepted_bidders: number;
+  attestor?: Attestor | null;
   denom: string;
   description?: string | null;
-  expires_at: Timestamp;
+  dispute?: DisputeWindow | null;
+  expires_at: Expiration;
+  group?: GroupRestriction | null;
+  max_bid_per_address?: Uint128 | null;
+  max_bidders?: number | null;
+  metadata?: PeriodMetadata | null;
+  min_increment?: Uint128 | null;
   minimum_bid: Uint128;
   name: string;
+  receipt_collection?: Addr | null;
+  refund_to?: RefundTarget;
+  starts_at?: Scheduled | null;
+  transferable_bids?: boolean;
+}
+export interface DisputeWindow {
+  duration: number;
+  guardian: Addr;
+}
+export interface GroupRestriction {
+  cap_per_weight?: Uint128 | null;
+  contract: Addr;
+}
+export interface PeriodMetadata {
+  category?: string | null;
+  image?: string | null;
+  links?: string[];
+  tags?: string[];
+  terms_hash?: string | null;
+}
+export interface ProceedsShare {
+  address: Addr;
+  bps: number;
+}
+export interface Attestation {
+  expiry: Timestamp;
+  max_amount: Uint128;
+  nonce: number;
+  signature: Binary;
 }
 export type QueryMsg = {
   bidding_period: {};
@@ -41,6 +262,81 @@ export type QueryMsg = {
   bid: {
     address: Addr;
   };
+} | {
+  schedule: {};
+} | {
+  pending_settlement: {};
+} | {
+  pause_status: {};
+} | {
+  stats: {};
+} | {
+  simulate_bid: {
+    address: Addr;
+    amount: Uint128;
+  };
+} | {
+  simulate_end_bidding: {
+    accepted_bids: Addr[];
+    sender?: Addr | null;
+    withdrawal_address?: Addr | null;
+  };
+} | {
+  bid_history: {
+    address: Addr;
+    limit?: number | null;
+    start_after?: number | null;
+  };
+} | {
+  denylist: {
+    limit?: number | null;
+    start_after?: Addr | null;
+  };
+} | {
+  receipt: {
+    address: Addr;
+    period_id: number;
+  };
+} | {
+  hooks: {};
+} | {
+  pending_admin_transfer: {};
+} | {
+  roles: {
+    address: Addr;
+  };
+} | {
+  treasury: {};
+} | {
+  proposal: {
+    proposal_id: number;
+  };
+} | {
+  proposals: {
+    limit?: number | null;
+    pending?: boolean | null;
+    start_after?: number | null;
+  };
+} | {
+  operators: {
+    include_expired?: boolean | null;
+    limit?: number | null;
+    owner: Addr;
+    start_after?: Addr | null;
+  };
+};
+export interface MigrateMsg {}
+export type SudoMsg = {
+  force_cancel: {};
+} | {
+  update_admins: {
+    admins: Addr[];
+    threshold?: number | null;
+  };
+} | {
+  pause: {};
+} | {
+  unpause: {};
 };
 export interface BidResponse {
   bid?: Bid | null;
@@ -48,10 +344,151 @@ export interface BidResponse {
 export interface Bid {
   amount: Uint128;
   bidder: Addr;
+  first_bid_at: Timestamp;
+  last_bid_at: Timestamp;
+  payers: PayerShare[];
+  terms_hash?: string | null;
+}
+export interface PayerShare {
+  amount: Uint128;
+  payer: Addr;
+}
+export interface BidHistoryResponse {
+  contributions: Contribution[];
+}
+export interface Contribution {
+  amount: Uint128;
+  height: number;
+  index: number;
+  payer: Addr;
+  period_id: number;
+  time: Timestamp;
 }
 export interface BiddingPeriodResponse {
   bidding_period?: BiddingPeriod | null;
+  period_id?: number | null;
 }
 export interface BidsResponse {
   bids: Bid[];
+}
+export interface DenylistResponse {
+  addresses: Addr[];
+}
+export interface HooksResponse {
+  hooks: HookInfo[];
+}
+export interface HookInfo {
+  address: Addr;
+  gas_limit: number;
+  on_error: HookFailure;
+}
+export interface OperatorsResponse {
+  operators: OperatorInfo[];
+}
+export interface OperatorInfo {
+  expires: Expiration;
+  operator: Addr;
+}
+export interface PauseStatusResponse {
+  emergency_withdraw_at?: Timestamp | null;
+  paused: boolean;
+  paused_at?: Timestamp | null;
+}
+export interface PendingAdminTransferResponse {
+  transfer?: AdminTransfer | null;
+}
+export interface AdminTransfer {
+  expiry: Expiration;
+  from: Addr;
+  to: Addr;
+}
+export interface PendingSettlementResponse {
+  settlement?: PendingSettlement | null;
+}
+export interface PendingSettlement {
+  denom: string;
+  guardian: Addr;
+  period_id: number;
+  period_name: string;
+  proceeds: Uint128;
+  receipt_collection?: Addr | null;
+  refund_to: RefundTarget;
+  releasable_at: Timestamp;
+  split?: ProceedsShare[];
+  winners: Bid[];
+  withdrawal_address: Addr;
+}
+export interface ProposalResponse {
+  proposal: Proposal;
+}
+export interface Proposal {
+  action: ProposalAction;
+  approvals: Addr[];
+  executed: boolean;
+  expires: Expiration;
+  id: number;
+  proposer: Addr;
+}
+export interface ProposalsResponse {
+  proposals: Proposal[];
+}
+export interface ReceiptResponse {
+  receipt?: Receipt | null;
+}
+export interface Receipt {
+  amount: Uint128;
+  claimed: boolean;
+  collection: Addr;
+  denom: string;
+  period_id: number;
+  period_name: string;
+  rank: number;
+}
+export interface RolesResponse {
+  roles: Role[];
+}
+export interface ScheduleResponse {
+  schedule?: Schedule | null;
+}
+export interface Schedule {
+  count: number;
+  interval: Duration;
+  started: number;
+  template: BiddingPeriod;
+}
+export interface SimulateBidResponse {
+  accepted: boolean;
+  error?: string | null;
+  rank: number;
+  top_up_needed: Uint128;
+}
+export interface SimulateEndBiddingResponse {
+  accepted_bids: Bid[];
+  error?: string | null;
+  held: boolean;
+  refunds: Payout[];
+  split: ProceedsShare[];
+  withdrawal_address?: Addr | null;
+  withdrawal_amount: Uint128;
+}
+export interface Payout {
+  address: Addr;
+  amount: Uint128;
+}
+export interface StatsResponse {
+  stats: PeriodStats;
+}
+export interface PeriodStats {
+  bidder_count: number;
+  cutoff_bid?: RankedBid | null;
+  highest_bid?: RankedBid | null;
+  total_deposited: Uint128;
+}
+export interface RankedBid {
+  amount: Uint128;
+  bidder: Addr;
+}
+export interface TreasuryResponse {
+  address?: Addr | null;
+  split: ProceedsShare[];
 }
\ No newline at end of file